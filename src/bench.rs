@@ -0,0 +1,174 @@
+use crate::parse::ParseError;
+use crate::registry::Day;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    pub min_runs: usize,
+    pub max_runs: usize,
+    pub budget: Duration,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Limits {
+            min_runs: 1,
+            max_runs: 20,
+            budget: Duration::from_secs(2),
+        }
+    }
+}
+
+fn median(mut samples: Vec<Duration>) -> Duration {
+    samples.sort();
+    samples[samples.len() / 2]
+}
+
+/// Times `run` until either `max_runs` runs or the time budget is used up,
+/// and returns the median.
+fn sample(limits: &Limits, mut run: impl FnMut() -> Duration) -> Duration {
+    let start = Instant::now();
+    let mut samples = Vec::new();
+    while samples.len() < limits.min_runs
+        || (samples.len() < limits.max_runs && start.elapsed() < limits.budget)
+    {
+        samples.push(run());
+    }
+    median(samples)
+}
+
+/// Times parsing and each part of `day` on `input`. Parsing is timed on its
+/// own and the parts are timed on an already parsed input. A part that fails
+/// is not timed and gives its error instead.
+pub fn measure(
+    day: &Day,
+    input: &str,
+    limits: &Limits,
+) -> Result<[Result<Timing, String>; 2], ParseError> {
+    let parsed = (day.parse)(input)?;
+    let parse = sample(limits, || {
+        let start = Instant::now();
+        let parsed = (day.parse)(input);
+        let elapsed = start.elapsed();
+        drop(parsed);
+        elapsed
+    });
+    Ok(std::array::from_fn(|part| {
+        day.parts[part](parsed.as_ref())?;
+        let solve = sample(limits, || {
+            let start = Instant::now();
+            let answer = day.parts[part](parsed.as_ref());
+            let elapsed = start.elapsed();
+            drop(answer);
+            elapsed
+        });
+        Ok(Timing { parse, solve })
+    }))
+}
+
+/// Saved timings keyed by day and part, stored as one
+/// `day part parse_ns solve_ns` line per entry.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Baseline {
+    pub timings: BTreeMap<(u32, usize), Timing>,
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> io::Result<Baseline> {
+        let mut baseline = Baseline::default();
+        for (i, line) in fs::read_to_string(path)?.lines().enumerate() {
+            let fields = line
+                .split_whitespace()
+                .map(|f| f.parse::<u64>())
+                .collect::<Result<Vec<_>, _>>();
+            match fields.as_deref() {
+                Ok([day, part, parse, solve]) => {
+                    baseline.timings.insert(
+                        (*day as u32, *part as usize),
+                        Timing {
+                            parse: Duration::from_nanos(*parse),
+                            solve: Duration::from_nanos(*solve),
+                        },
+                    );
+                }
+                _ => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("{}:{}: malformed baseline entry", path.display(), i + 1),
+                    ))
+                }
+            }
+        }
+        Ok(baseline)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut out = String::new();
+        for ((day, part), timing) in self.timings.iter() {
+            out += &format!(
+                "{} {} {} {}\n",
+                day,
+                part,
+                timing.parse.as_nanos(),
+                timing.solve.as_nanos()
+            );
+        }
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, out)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Change {
+    New,
+    Unchanged(f64),
+    Faster(f64),
+    Slower(f64),
+}
+
+impl Change {
+    /// Compares `new` against `old`; relative changes within `threshold`
+    /// (e.g. 0.1 for 10%) count as unchanged.
+    pub fn between(old: Option<Duration>, new: Duration, threshold: f64) -> Change {
+        let old = match old {
+            Some(old) if !old.is_zero() => old,
+            _ => return Change::New,
+        };
+        let ratio = new.as_secs_f64() / old.as_secs_f64() - 1.0;
+        if ratio > threshold {
+            Change::Slower(ratio)
+        } else if ratio < -threshold {
+            Change::Faster(ratio)
+        } else {
+            Change::Unchanged(ratio)
+        }
+    }
+
+    pub fn is_regression(&self) -> bool {
+        matches!(self, Change::Slower(_))
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Change::New => write!(f, "new"),
+            Change::Unchanged(r) => write!(f, "{:+.1}%", r * 100.0),
+            Change::Faster(r) => write!(f, "{:+.1}% faster", r * 100.0),
+            Change::Slower(r) => write!(f, "{:+.1}% SLOWER", r * 100.0),
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use advent_of_code_2021::bench::{measure, Baseline, Change, Limits};
+use advent_of_code_2021::generate::generate;
+use advent_of_code_2021::input::{read_input, Input, InputSource};
+use advent_of_code_2021::registry::{get_day, Day, DAYS};
+use std::env;
+use std::path::PathBuf;
+use std::process;
+use std::time::Duration;
+
+const USAGE: &str = "Usage: aoc-bench [--day <1-23>] [--test | --generate <size> [--seed <n>]] \
+[--runs <n>] [--budget <secs>] [--baseline <path>] [--threshold <percent>] [--save]";
+
+struct Args {
+    days: Vec<&'static Day>,
+    source: InputSource,
+    generate: Option<(usize, u64)>,
+    limits: Limits,
+    baseline: PathBuf,
+    threshold: f64,
+    save: bool,
+}
+
+fn parse_value<T: std::str::FromStr>(value: Option<String>) -> Result<T, String> {
+    match value {
+        Some(x) => x.parse().map_err(|_| format!("Invalid value: {}", x)),
+        None => Err("Missing value".to_string()),
+    }
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut result = Args {
+        days: DAYS.iter().collect(),
+        source: InputSource::Default,
+        generate: None,
+        limits: Limits::default(),
+        baseline: PathBuf::new(),
+        threshold: 10.0,
+        save: false,
+    };
+    let mut baseline = None;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => {
+                let day = parse_value(args.next())?;
+                result.days = vec![get_day(day).ok_or(format!("Invalid value: {}", day))?];
+            }
+            "--test" | "-t" => result.source = InputSource::Test,
+            "--generate" | "-g" => {
+                let seed = result.generate.map_or(0, |(_, seed)| seed);
+                result.generate = Some((parse_value(args.next())?, seed));
+            }
+            "--seed" => {
+                let size = result.generate.map_or(1000, |(size, _)| size);
+                result.generate = Some((size, parse_value(args.next())?));
+            }
+            "--runs" | "-n" => result.limits.max_runs = parse_value(args.next())?,
+            "--budget" => result.limits.budget = Duration::from_secs_f64(parse_value(args.next())?),
+            "--baseline" => baseline = Some(PathBuf::from(parse_value::<String>(args.next())?)),
+            "--threshold" => result.threshold = parse_value(args.next())?,
+            "--save" => result.save = true,
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+    // Generated inputs are timed against their own baseline.
+    result.baseline = baseline.unwrap_or_else(|| match result.generate {
+        Some((size, seed)) => PathBuf::from(format!("bench/baseline-{}-{}.txt", size, seed)),
+        None => PathBuf::from("bench/baseline.txt"),
+    });
+    Ok(result)
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(error) => {
+            eprintln!("{}\n{}", error, USAGE);
+            process::exit(2);
+        }
+    };
+
+    let baseline = if args.baseline.exists() {
+        match Baseline::load(&args.baseline) {
+            Ok(baseline) => baseline,
+            Err(error) => {
+                eprintln!("{}", error);
+                process::exit(1);
+            }
+        }
+    } else {
+        Baseline::default()
+    };
+    let mut current = baseline.clone();
+    let mut regressions = 0;
+    let mut failures = 0;
+
+    println!(
+        "{:>3} {:>4} {:>12} {:>12}  vs baseline",
+        "Day", "Part", "Parse", "Solve"
+    );
+    for day in args.days.iter() {
+        let input = match args.generate {
+            Some((size, seed)) => Ok(Input {
+                name: format!("<generated day {} size {} seed {}>", day.day, size, seed),
+                text: generate(day.day, size, seed).unwrap_or_default(),
+            }),
+            None => read_input(day.day, &args.source),
+        };
+        let input = match input {
+            Ok(input) => input,
+            Err(error) => {
+                eprintln!("{}", error);
+                continue;
+            }
+        };
+        let timings = match measure(day, &input.text, &args.limits) {
+            Ok(timings) => timings,
+            Err(error) => {
+                eprintln!("{}", error.in_file(&input.name));
+                continue;
+            }
+        };
+        for (i, timing) in timings.iter().enumerate() {
+            let key = (day.day, i + 1);
+            let timing = match timing {
+                Ok(timing) => timing,
+                Err(error) => {
+                    eprintln!("Day {} part {}: {}: {}", day.day, i + 1, input.name, error);
+                    failures += 1;
+                    continue;
+                }
+            };
+            let old = baseline.timings.get(&key);
+            let threshold = args.threshold / 100.0;
+            let parse = Change::between(old.map(|t| t.parse), timing.parse, threshold);
+            let solve = Change::between(old.map(|t| t.solve), timing.solve, threshold);
+            if parse.is_regression() || solve.is_regression() {
+                regressions += 1;
+            }
+            println!(
+                "{:>3} {:>4} {:>12.2?} {:>12.2?}  parse {}, solve {}",
+                day.day,
+                i + 1,
+                timing.parse,
+                timing.solve,
+                parse,
+                solve
+            );
+            current.timings.insert(key, *timing);
+        }
+    }
+
+    if args.save {
+        if let Err(error) = current.save(&args.baseline) {
+            eprintln!("Could not save baseline: {}", error);
+            process::exit(1);
+        }
+        println!("Saved baseline to {}", args.baseline.display());
+    } else if regressions > 0 {
+        println!(
+            "{} regression(s) against {}",
+            regressions,
+            args.baseline.display()
+        );
+        process::exit(1);
+    }
+    if failures > 0 {
+        eprintln!("{} part(s) failed", failures);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,87 @@
+use advent_of_code_2021::day4::{self, Replay, Rules};
+use advent_of_code_2021::input::{read_input, InputSource};
+use std::env;
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+use std::process;
+
+const USAGE: &str = "Usage: aoc-bingo [--diagonals] [--draw <n>] [--test | <path>]
+
+Replays day 4 one draw at a time. At the prompt, press enter for the next
+draw, type a number to jump to that draw, b to go back one draw or q to quit.";
+
+struct Args {
+    source: InputSource,
+    rules: Rules,
+    draw: usize,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut source = InputSource::Default;
+    let mut rules = Rules::default();
+    let mut draw = 0;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--diagonals" => rules.diagonals = true,
+            "--draw" | "-n" => {
+                let value = args.next().ok_or("Missing value")?;
+                draw = value
+                    .parse()
+                    .map_err(|_| format!("Invalid value: {}", value))?;
+            }
+            "--test" | "-t" => source = InputSource::Test,
+            _ if arg.starts_with('-') => return Err(format!("Unknown argument: {}", arg)),
+            _ => source = InputSource::Path(PathBuf::from(arg)),
+        }
+    }
+    Ok(Args {
+        source,
+        rules,
+        draw,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(error) => {
+            eprintln!("{}\n{}", error, USAGE);
+            process::exit(2);
+        }
+    };
+    let input = read_input(4, &args.source).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        process::exit(1);
+    });
+    let bingo = day4::parse(&input.text).unwrap_or_else(|error| {
+        eprintln!("{}", error.in_file(&input.name));
+        process::exit(1);
+    });
+
+    let mut replay = Replay::new(&bingo, args.rules);
+    replay.seek(args.draw);
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    loop {
+        print!("{}\n> ", replay.render());
+        io::stdout().flush().unwrap();
+        let line = match lines.next() {
+            Some(Ok(line)) => line,
+            _ => break,
+        };
+        match line.trim() {
+            "" => {
+                if replay.step().is_none() {
+                    println!("No draws left");
+                }
+            }
+            "b" => replay.seek(replay.turn().saturating_sub(1)),
+            "q" => break,
+            command => match command.parse() {
+                Ok(draw) => replay.seek(draw),
+                Err(_) => println!("Unknown command: {}", command),
+            },
+        }
+    }
+}
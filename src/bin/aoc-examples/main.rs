@@ -0,0 +1,113 @@
+use advent_of_code_2021::examples::{extract, Answers};
+use advent_of_code_2021::input;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+
+const USAGE: &str = "Usage: aoc-examples --day <n> [--block <n>] [--inputs <dir>] [--dry-run] \
+<puzzle.html|puzzle.md>";
+
+struct Args {
+    day: u32,
+    block: usize,
+    inputs: PathBuf,
+    dry_run: bool,
+    page: PathBuf,
+}
+
+fn parse_value<T: std::str::FromStr>(value: Option<String>) -> Result<T, String> {
+    match value {
+        Some(x) => x.parse().map_err(|_| format!("Invalid value: {}", x)),
+        None => Err("Missing value".to_string()),
+    }
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut day = None;
+    let mut block = 1;
+    let mut inputs = input::inputs_dir();
+    let mut dry_run = false;
+    let mut page = None;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => day = Some(parse_value(args.next())?),
+            "--block" | "-b" => block = parse_value(args.next())?,
+            "--inputs" => inputs = PathBuf::from(parse_value::<String>(args.next())?),
+            "--dry-run" | "-n" => dry_run = true,
+            _ if arg.starts_with('-') => return Err(format!("Unknown argument: {}", arg)),
+            _ => page = Some(PathBuf::from(arg)),
+        }
+    }
+    match (day, page) {
+        (None, _) => Err("Missing --day".to_string()),
+        (_, None) => Err("Missing puzzle file".to_string()),
+        (_, _) if block == 0 => Err("Blocks are numbered from 1".to_string()),
+        (Some(day), Some(page)) => Ok(Args {
+            day,
+            block,
+            inputs,
+            dry_run,
+            page,
+        }),
+    }
+}
+
+fn run(args: &Args) -> Result<(), String> {
+    let page =
+        fs::read_to_string(&args.page).map_err(|e| format!("{}: {}", args.page.display(), e))?;
+    let examples = extract(&page);
+    let block = examples.blocks.get(args.block - 1).ok_or(format!(
+        "{} has {} example block(s)",
+        args.page.display(),
+        examples.blocks.len()
+    ))?;
+
+    let input_path = args.inputs.join(format!("{}_test.txt", args.day));
+    let table_path = args.inputs.join("examples.txt");
+    let mut table = if table_path.exists() {
+        Answers::load(&table_path).map_err(|e| e.to_string())?
+    } else {
+        Answers::default()
+    };
+    for (part, answer) in examples.answers.iter().enumerate() {
+        match answer {
+            Some(answer) => {
+                println!("Part {}: {}", part + 1, answer);
+                table.answers.insert((args.day, part + 1), answer.clone());
+            }
+            None => println!("Part {}: no answer found", part + 1),
+        }
+    }
+    print!("{}", block);
+
+    if !args.dry_run {
+        fs::create_dir_all(&args.inputs)
+            .map_err(|e| format!("{}: {}", args.inputs.display(), e))?;
+        fs::write(&input_path, block).map_err(|e| format!("{}: {}", input_path.display(), e))?;
+        table
+            .save(&table_path)
+            .map_err(|e| format!("{}: {}", table_path.display(), e))?;
+        println!(
+            "Wrote {} and updated {}",
+            input_path.display(),
+            table_path.display()
+        );
+    }
+    Ok(())
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(error) => {
+            eprintln!("{}\n{}", error, USAGE);
+            process::exit(2);
+        }
+    };
+    if let Err(error) = run(&args) {
+        eprintln!("{}", error);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,58 @@
+use advent_of_code_2021::generate::{default_size, generate};
+use std::env;
+use std::process;
+
+const USAGE: &str = "Usage: aoc-gen --day <1-23> [--size <n>] [--seed <n>]";
+
+struct Args {
+    day: u32,
+    size: usize,
+    seed: u64,
+}
+
+fn parse_value<T: std::str::FromStr>(value: Option<String>) -> Result<T, String> {
+    match value {
+        Some(x) => x.parse().map_err(|_| format!("Invalid value: {}", x)),
+        None => Err("Missing value".to_string()),
+    }
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut day = None;
+    let mut size = None;
+    let mut seed = 0;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => day = Some(parse_value(args.next())?),
+            "--size" | "-n" => size = Some(parse_value(args.next())?),
+            "--seed" | "-s" => seed = parse_value(args.next())?,
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+    match day {
+        Some(day) => Ok(Args {
+            day,
+            size: size.unwrap_or_else(|| default_size(day)),
+            seed,
+        }),
+        None => Err("Missing --day".to_string()),
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(error) => {
+            eprintln!("{}\n{}", error, USAGE);
+            process::exit(2);
+        }
+    };
+    match generate(args.day, args.size, args.seed) {
+        Some(input) => print!("{}", input),
+        None => {
+            eprintln!("No generator for day {}\n{}", args.day, USAGE);
+            process::exit(2);
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use advent_of_code_2021::day5::{self, Heatmap};
+use advent_of_code_2021::input::{read_input, InputSource};
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+
+const USAGE: &str = "Usage: aoc-vents [--axis-aligned] [--size <n>] [--pgm <file>] [--ppm <file>] \
+[--test | <path>]
+
+Prints a heatmap of the day 5 vent lines, cropped to the covered points and
+shrunk to at most <n> (default 80) cells across, and optionally writes it as
+a PGM or PPM image.";
+
+struct Args {
+    source: InputSource,
+    axis_aligned: bool,
+    size: usize,
+    pgm: Option<PathBuf>,
+    ppm: Option<PathBuf>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut source = InputSource::Default;
+    let mut axis_aligned = false;
+    let mut size = 80;
+    let mut pgm = None;
+    let mut ppm = None;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--axis-aligned" => axis_aligned = true,
+            "--size" | "-n" => {
+                let value = args.next().ok_or("Missing value")?;
+                size = value
+                    .parse()
+                    .map_err(|_| format!("Invalid value: {}", value))?;
+            }
+            "--pgm" => pgm = Some(PathBuf::from(args.next().ok_or("Missing value")?)),
+            "--ppm" => ppm = Some(PathBuf::from(args.next().ok_or("Missing value")?)),
+            "--test" | "-t" => source = InputSource::Test,
+            _ if arg.starts_with('-') => return Err(format!("Unknown argument: {}", arg)),
+            _ => source = InputSource::Path(PathBuf::from(arg)),
+        }
+    }
+    Ok(Args {
+        source,
+        axis_aligned,
+        size,
+        pgm,
+        ppm,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(error) => {
+            eprintln!("{}\n{}", error, USAGE);
+            process::exit(2);
+        }
+    };
+    let input = read_input(5, &args.source).unwrap_or_else(|error| {
+        eprintln!("{}", error);
+        process::exit(1);
+    });
+    let lines = day5::parse(&input.text).unwrap_or_else(|error| {
+        eprintln!("{}", error.in_file(&input.name));
+        process::exit(1);
+    });
+
+    let lines = lines
+        .iter()
+        .filter(|line| !args.axis_aligned || line.is_axis_aligned());
+    let heatmap = Heatmap::new(lines, args.size);
+    print!("{}", heatmap.to_ascii());
+
+    let images = [
+        (&args.pgm, args.pgm.as_ref().map(|_| heatmap.to_pgm())),
+        (&args.ppm, args.ppm.as_ref().map(|_| heatmap.to_ppm())),
+    ];
+    for (path, image) in images {
+        if let (Some(path), Some(image)) = (path, image) {
+            if let Err(error) = fs::write(path, image) {
+                eprintln!("Could not write {}: {}", path.display(), error);
+                process::exit(1);
+            }
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use advent_of_code_2021::fetch::Fetcher;
+use advent_of_code_2021::input::{self, read_input, Input, InputSource};
+use advent_of_code_2021::logging::Logger;
+use advent_of_code_2021::registry::{get_day, Day, DAYS};
+use advent_of_code_2021::report::Record;
+use advent_of_code_2021::submit::{Check, Ledger, Response, Submitter};
+use log::LevelFilter;
+use std::collections::HashMap;
+use std::env;
+use std::process;
+use std::time::Instant;
+
+const USAGE: &str = "Usage: aoc --day <1-23|all> [--part <1|2|all>] [--test | --input <path|->] \
+[--fetch | --refresh] [--submit] \
+[--quiet | --verbose] [--trace <day>]... [--format <human|json>]";
+
+#[derive(Clone, Copy)]
+enum Format {
+    Human,
+    Json,
+}
+
+enum Selection {
+    One(u32),
+    All,
+}
+
+/// How missing inputs are downloaded, for `InputSource::Default` only.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Fetch {
+    Never,
+    Missing,
+    Always,
+}
+
+struct Args {
+    day: Selection,
+    part: Selection,
+    source: InputSource,
+    fetch: Fetch,
+    submit: bool,
+    log_level: LevelFilter,
+    traced_days: HashMap<u32, LevelFilter>,
+    format: Format,
+}
+
+fn parse_selection(value: Option<String>, max: u32) -> Result<Selection, String> {
+    match value.as_deref() {
+        Some("all") => Ok(Selection::All),
+        Some(x) => match x.parse() {
+            Ok(n) if (1..=max).contains(&n) => Ok(Selection::One(n)),
+            _ => Err(format!("Invalid value: {}", x)),
+        },
+        None => Err("Missing value".to_string()),
+    }
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut day = None;
+    let mut part = Selection::All;
+    let mut source = InputSource::Default;
+    let mut fetch = Fetch::Never;
+    let mut submit = false;
+    let mut log_level = LevelFilter::Warn;
+    let mut traced_days = HashMap::new();
+    let mut format = Format::Human;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => day = Some(parse_selection(args.next(), DAYS.len() as u32)?),
+            "--part" | "-p" => part = parse_selection(args.next(), 2)?,
+            "--test" | "-t" => source = InputSource::Test,
+            "--fetch" => fetch = Fetch::Missing,
+            "--refresh" => fetch = Fetch::Always,
+            "--submit" => submit = true,
+            "--format" | "-f" => {
+                format = match args.next().as_deref() {
+                    Some("human") => Format::Human,
+                    Some("json") => Format::Json,
+                    Some(x) => return Err(format!("Invalid value: {}", x)),
+                    None => return Err("Missing value".to_string()),
+                }
+            }
+            "--quiet" | "-q" => log_level = LevelFilter::Off,
+            "--verbose" | "-v" => log_level = LevelFilter::Debug,
+            "--trace" => match parse_selection(args.next(), DAYS.len() as u32)? {
+                Selection::One(day) => {
+                    traced_days.insert(day, LevelFilter::Trace);
+                }
+                Selection::All => log_level = LevelFilter::Trace,
+            },
+            "--input" | "-i" => {
+                source = match args.next().as_deref() {
+                    Some("-") => InputSource::Stdin,
+                    Some(path) => InputSource::Path(path.into()),
+                    None => return Err("Missing value".to_string()),
+                }
+            }
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+    if submit && !matches!((&day, &part), (Some(Selection::One(_)), Selection::One(_))) {
+        return Err("--submit needs a single --day and --part".to_string());
+    }
+    match (day, &source) {
+        (None, _) => Err("Missing --day".to_string()),
+        (Some(Selection::All), InputSource::Path(_) | InputSource::Stdin) => {
+            Err("--input needs a single --day".to_string())
+        }
+        (Some(_), InputSource::Test | InputSource::Path(_) | InputSource::Stdin)
+            if fetch != Fetch::Never =>
+        {
+            Err("--fetch only applies to the default inputs".to_string())
+        }
+        (Some(day), _) => Ok(Args {
+            day,
+            part,
+            source,
+            fetch,
+            submit,
+            log_level,
+            traced_days,
+            format,
+        }),
+    }
+}
+
+fn run_part(day: &Day, part: u32, input: &Input, format: Format) -> Result<String, String> {
+    let start = Instant::now();
+    let answer = day
+        .run(part as usize, &input.text)
+        .map_err(|error| error.in_file(&input.name).to_string())?;
+    let record = Record::new(day.day, part as usize, answer, input, start.elapsed());
+    match format {
+        Format::Human => println!("{}", record.to_human()),
+        Format::Json => println!("{}", record.to_json()),
+    }
+    Ok(record.answer)
+}
+
+/// Submits `answer` unless the ledger already rules it out, and records the
+/// verdict.
+fn submit_answer(day: u32, part: usize, answer: &str) -> Result<(), String> {
+    if answer.is_empty() || answer.contains(char::is_whitespace) {
+        return Err(format!("Cannot submit {:?}", answer));
+    }
+    // Kept next to the cached inputs, wherever `aoc` is run from.
+    let path = input::inputs_dir().join("submissions.txt");
+    let mut ledger = if path.exists() {
+        Ledger::load(&path).map_err(|e| format!("{}: {}", path.display(), e))?
+    } else {
+        Ledger::default()
+    };
+    match ledger.check(day, part, answer) {
+        Check::Unknown => {}
+        Check::Submitted(verdict) => {
+            return Err(format!("{} was already submitted: {}", answer, verdict));
+        }
+        Check::Solved(correct) => {
+            return Err(format!("Already solved, the answer was {}", correct));
+        }
+        Check::OutOfBounds(low, high) => {
+            let show = |b: Option<i128>| b.map_or("?".to_string(), |b| b.to_string());
+            return Err(format!(
+                "{} is outside the known range ({}, {}), not submitting",
+                answer,
+                show(low),
+                show(high)
+            ));
+        }
+    }
+    let response = Submitter::from_env()
+        .submit(day, part, answer)
+        .map_err(|e| e.to_string())?;
+    println!("Day {} part {}: {} is {}", day, part, answer, response);
+    if let Response::Verdict(verdict) = response {
+        ledger.record(day, part, answer, verdict);
+        ledger
+            .save(&path)
+            .map_err(|e| format!("{}: {}", path.display(), e))?;
+    }
+    Ok(())
+}
+
+fn load_input(day: u32, args: &Args) -> Result<Input, String> {
+    match args.fetch {
+        Fetch::Never => read_input(day, &args.source).map_err(|e| e.to_string()),
+        fetch => Fetcher::from_env()
+            .fetch(day, fetch == Fetch::Always)
+            .map_err(|e| e.to_string()),
+    }
+}
+
+fn run_day(day: &Day, args: &Args) -> Result<(), String> {
+    let input = load_input(day.day, args)?;
+    let (part, format) = (&args.part, args.format);
+    match part {
+        Selection::One(part) => {
+            let answer = run_part(day, *part, &input, format)?;
+            if args.submit {
+                submit_answer(day.day, *part as usize, &answer)?;
+            }
+        }
+        Selection::All => {
+            // Part 2 runs even when part 1 fails.
+            let first = run_part(day, 1, &input, format);
+            run_part(day, 2, &input, format)?;
+            first?;
+        }
+    }
+    Ok(())
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(error) => {
+            eprintln!("{}\n{}", error, USAGE);
+            process::exit(2);
+        }
+    };
+    Logger::new(args.log_level, args.traced_days.clone())
+        .init()
+        .unwrap();
+    let days = match args.day {
+        Selection::One(day) => vec![get_day(day).unwrap()],
+        Selection::All => DAYS.iter().collect(),
+    };
+    let mut failed = Vec::new();
+    for day in days {
+        if let Err(error) = run_day(day, &args) {
+            eprintln!("{}", error);
+            failed.push(day.day.to_string());
+        }
+    }
+    if !failed.is_empty() {
+        if failed.len() > 1 {
+            eprintln!("Failed days: {}", failed.join(", "));
+        }
+        process::exit(1);
+    }
+}
@@ -0,0 +1,143 @@
+use crate::parse::ParseError;
+use crate::scan;
+use std::collections::VecDeque;
+
+pub fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+    scan::integers(input, 1, b"\n", "integer")
+}
+
+/// Statistics of one window of consecutive readings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Window {
+    pub sum: i64,
+    pub min: i64,
+    pub max: i64,
+    pub len: usize,
+}
+
+impl Window {
+    pub fn mean(&self) -> f64 {
+        self.sum as f64 / self.len as f64
+    }
+}
+
+/// Sliding windows of a fixed width over a stream of readings, each computed
+/// in amortized constant time. Yields nothing if there are fewer readings
+/// than the width.
+pub struct Windows<I> {
+    readings: I,
+    width: usize,
+    index: usize,
+    values: VecDeque<i64>,
+    sum: i64,
+    // Indices and values of the readings that can still be the minimum or
+    // maximum of a later window, in increasing and decreasing order.
+    mins: VecDeque<(usize, i64)>,
+    maxs: VecDeque<(usize, i64)>,
+}
+
+pub fn windows<I: IntoIterator<Item = i64>>(readings: I, width: usize) -> Windows<I::IntoIter> {
+    assert!(width > 0, "window width must be positive");
+    Windows {
+        readings: readings.into_iter(),
+        width,
+        index: 0,
+        values: VecDeque::with_capacity(width),
+        sum: 0,
+        mins: VecDeque::new(),
+        maxs: VecDeque::new(),
+    }
+}
+
+impl<I: Iterator<Item = i64>> Iterator for Windows<I> {
+    type Item = Window;
+
+    fn next(&mut self) -> Option<Window> {
+        loop {
+            let reading = self.readings.next()?;
+            if self.values.len() == self.width {
+                self.sum -= self.values.pop_front().unwrap();
+            }
+            self.values.push_back(reading);
+            self.sum += reading;
+
+            let first = (self.index + 1).saturating_sub(self.width);
+            while self.mins.back().is_some_and(|&(_, v)| v >= reading) {
+                self.mins.pop_back();
+            }
+            self.mins.push_back((self.index, reading));
+            while self.mins.front().is_some_and(|&(i, _)| i < first) {
+                self.mins.pop_front();
+            }
+            while self.maxs.back().is_some_and(|&(_, v)| v <= reading) {
+                self.maxs.pop_back();
+            }
+            self.maxs.push_back((self.index, reading));
+            while self.maxs.front().is_some_and(|&(i, _)| i < first) {
+                self.maxs.pop_front();
+            }
+            self.index += 1;
+
+            if self.values.len() == self.width {
+                return Some(Window {
+                    sum: self.sum,
+                    min: self.mins[0].1,
+                    max: self.maxs[0].1,
+                    len: self.width,
+                });
+            }
+        }
+    }
+}
+
+/// How consecutive readings change. Runs are counted in readings, so a
+/// single reading is a run of length one.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Changes {
+    pub increases: usize,
+    pub decreases: usize,
+    pub unchanged: usize,
+    pub longest_increasing: usize,
+    pub longest_decreasing: usize,
+}
+
+pub fn changes<I: IntoIterator<Item = i64>>(readings: I) -> Changes {
+    let mut changes = Changes::default();
+    let mut last = None;
+    let (mut increasing, mut decreasing) = (0, 0);
+    for reading in readings {
+        match last {
+            Some(last) if reading > last => {
+                changes.increases += 1;
+                increasing += 1;
+                decreasing = 1;
+            }
+            Some(last) if reading < last => {
+                changes.decreases += 1;
+                increasing = 1;
+                decreasing += 1;
+            }
+            Some(_) => {
+                changes.unchanged += 1;
+                increasing = 1;
+                decreasing = 1;
+            }
+            None => {
+                increasing = 1;
+                decreasing = 1;
+            }
+        }
+        changes.longest_increasing = changes.longest_increasing.max(increasing);
+        changes.longest_decreasing = changes.longest_decreasing.max(decreasing);
+        last = Some(reading);
+    }
+    changes
+}
+
+pub fn part1(input: &[i64]) -> usize {
+    changes(input.iter().copied()).increases
+}
+
+pub fn part2(input: &[i64]) -> usize {
+    changes(windows(input.iter().copied(), 3).map(|window| window.sum)).increases
+}
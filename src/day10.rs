@@ -0,0 +1,129 @@
+use crate::parse::{self, ParseError};
+use phf::{phf_map, Map};
+use std::fmt;
+
+static DELIMITERS: Map<char, char> = phf_map! {
+    '(' => ')',
+    '[' => ']',
+    '{' => '}',
+    '<' => '>',
+};
+
+static CORRUPTION_VALUES: Map<char, u32> = phf_map! {
+    ')' => 3,
+    ']' => 57,
+    '}' => 1197,
+    '>' => 25137,
+};
+
+static COMPLETION_VALUES: Map<char, u64> = phf_map! {
+    ')' => 1,
+    ']' => 2,
+    '}' => 3,
+    '>' => 4,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BracketError {
+    /// A closing bracket with no chunk open, at a line and column.
+    Unopened(usize, usize),
+    /// No line is incomplete, so there is no middle score.
+    NoIncompleteLines,
+}
+
+impl fmt::Display for BracketError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BracketError::Unopened(line, column) => {
+                write!(f, "{}:{}: closing bracket with no chunk open", line, column)
+            }
+            BracketError::NoIncompleteLines => write!(f, "No line is incomplete"),
+        }
+    }
+}
+
+impl std::error::Error for BracketError {}
+
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    let mut lines = Vec::new();
+    for (i, line) in parse::lines(input) {
+        if let Some((j, c)) = line
+            .char_indices()
+            .find(|(_, c)| !DELIMITERS.contains_key(c) && !CORRUPTION_VALUES.contains_key(c))
+        {
+            return Err(ParseError::at(
+                i,
+                line,
+                &line[j..j + c.len_utf8()],
+                "bracket",
+            ));
+        }
+        lines.push(line.to_string());
+    }
+    Ok(lines)
+}
+
+enum Status {
+    /// The first illegal closing bracket.
+    Corrupted(char),
+    /// The chunks left open, innermost last.
+    Incomplete(Vec<char>),
+}
+
+fn check_line(line_no: usize, line: &str) -> Result<Status, BracketError> {
+    let mut stack = vec![];
+    for (i, c) in line.chars().enumerate() {
+        if DELIMITERS.contains_key(&c) {
+            stack.push(c);
+        } else {
+            match stack.last() {
+                None => return Err(BracketError::Unopened(line_no, i + 1)),
+                Some(open) if c == DELIMITERS[open] => {
+                    stack.pop();
+                }
+                Some(_) => return Ok(Status::Corrupted(c)),
+            }
+        }
+    }
+    Ok(Status::Incomplete(stack))
+}
+
+pub fn get_corruption_value(line_no: usize, line: &str) -> Result<u32, BracketError> {
+    Ok(match check_line(line_no, line)? {
+        Status::Corrupted(c) => CORRUPTION_VALUES[&c],
+        Status::Incomplete(_) => 0,
+    })
+}
+
+pub fn get_completion_score(line_no: usize, line: &str) -> Result<Option<u64>, BracketError> {
+    let stack = match check_line(line_no, line)? {
+        Status::Corrupted(_) => return Ok(None),
+        Status::Incomplete(stack) => stack,
+    };
+    let mut score = 0;
+    for c in stack.iter().rev() {
+        score *= 5;
+        score += COMPLETION_VALUES[&DELIMITERS[c]];
+    }
+    Ok(Some(score))
+}
+
+pub fn part1(input: &[String]) -> Result<u32, BracketError> {
+    let mut sum = 0;
+    for (i, line) in input.iter().enumerate() {
+        sum += get_corruption_value(i + 1, line)?;
+    }
+    Ok(sum)
+}
+
+pub fn part2(input: &[String]) -> Result<u64, BracketError> {
+    let mut scores = vec![];
+    for (i, line) in input.iter().enumerate() {
+        scores.extend(get_completion_score(i + 1, line)?);
+    }
+    if scores.is_empty() {
+        return Err(BracketError::NoIncompleteLines);
+    }
+    scores.sort_unstable();
+    Ok(scores[scores.len() / 2])
+}
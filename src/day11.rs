@@ -0,0 +1,74 @@
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use std::fmt;
+
+/// The number of steps part 2 simulates before giving up.
+const MAX_STEPS: u32 = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoSync;
+
+impl fmt::Display for NoSync {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "The octopuses do not all flash at once within {} steps",
+            MAX_STEPS
+        )
+    }
+}
+
+impl std::error::Error for NoSync {}
+
+pub fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse_digits(input)
+}
+
+pub fn simulate_step(map: &mut Grid<u8>) -> u32 {
+    let mut to_flash = Vec::new();
+    for (pos, v) in map.indexed_iter_mut() {
+        *v += 1;
+        if *v > 9 {
+            to_flash.push(pos);
+        }
+    }
+    let mut flashed = map.map(|_| false);
+    while let Some(pos) = to_flash.pop() {
+        if flashed[pos] {
+            continue;
+        }
+        flashed[pos] = true;
+        for n in map.neighbors8(pos) {
+            map[n] += 1;
+            if map[n] > 9 && !flashed[n] {
+                to_flash.push(n);
+            }
+        }
+    }
+
+    for (v, &f) in map.iter_mut().zip(flashed.iter()) {
+        if f {
+            *v = 0;
+        }
+    }
+    flashed.iter().filter(|&&f| f).count() as u32
+}
+
+pub fn part1(input: &Grid<u8>) -> u32 {
+    let mut map = input.clone();
+    let mut sum = 0;
+    for _ in 0..100 {
+        sum += simulate_step(&mut map);
+    }
+    sum
+}
+
+pub fn part2(input: &Grid<u8>) -> Result<u32, NoSync> {
+    let mut map = input.clone();
+    for step in 0..MAX_STEPS {
+        if simulate_step(&mut map) as usize == map.len() {
+            return Ok(step + 1);
+        }
+    }
+    Err(NoSync)
+}
@@ -0,0 +1,219 @@
+use crate::parse::{self, ParseError};
+use graphlib::{Graph, VertexId};
+use log::trace;
+
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+pub struct BidirectionalMap<K, V> {
+    right_to_left: HashMap<K, V>,
+    left_to_right: HashMap<V, K>,
+}
+
+impl<K, V> BidirectionalMap<K, V>
+where
+    K: Hash + Eq + Clone,
+    V: Hash + Eq + Clone,
+{
+    pub fn new() -> BidirectionalMap<K, V> {
+        BidirectionalMap {
+            right_to_left: HashMap::new(),
+            left_to_right: HashMap::new(),
+        }
+    }
+
+    pub fn remove_by_key(&mut self, k: K) -> Option<V> {
+        let v = self.right_to_left.remove(&k)?;
+        let _ = self.left_to_right.remove(&v);
+        Some(v)
+    }
+
+    pub fn remove_by_val(&mut self, v: V) -> Option<K> {
+        let k = self.left_to_right.remove(&v)?;
+        let _ = self.right_to_left.remove(&k);
+        Some(k)
+    }
+
+    pub fn get_value(&self, key: K) -> Option<&V> {
+        self.right_to_left.get(&key)
+    }
+
+    pub fn get_key(&self, val: V) -> Option<&K> {
+        self.left_to_right.get(&val)
+    }
+
+    pub fn insert(&mut self, k: K, v: V) -> Option<V> {
+        let _ = self.left_to_right.insert(v.clone(), k.clone());
+        self.right_to_left.insert(k, v)
+    }
+}
+
+impl<K, V> Default for BidirectionalMap<K, V>
+where
+    K: Hash + Eq + Clone,
+    V: Hash + Eq + Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub type Caves = (Graph<bool>, BidirectionalMap<VertexId, String>);
+
+fn get_or_add_vertex(
+    name: &str,
+    graph: &mut Graph<bool>,
+    id_to_name_map: &mut BidirectionalMap<VertexId, String>,
+) -> VertexId {
+    match id_to_name_map.get_key(name.to_string()) {
+        Some(x) => *x,
+        None => {
+            let large = name.chars().all(char::is_uppercase);
+            let id = graph.add_vertex(large);
+            id_to_name_map.insert(id, name.to_string());
+            id
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MissingCave(pub &'static str);
+
+impl fmt::Display for MissingCave {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "No cave is named {}", self.0)
+    }
+}
+
+impl std::error::Error for MissingCave {}
+
+pub fn parse(input: &str) -> Result<Caves, ParseError> {
+    let mut graph = Graph::new();
+    let mut id_to_name_map = BidirectionalMap::new();
+
+    for (i, line) in parse::lines(input) {
+        let [start, end] = parse::fields(i, line, line, "-")?;
+        for name in [start, end] {
+            if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphabetic()) {
+                return Err(ParseError::at(i, line, name, "cave name"));
+            }
+        }
+        let id1 = get_or_add_vertex(start, &mut graph, &mut id_to_name_map);
+        let id2 = get_or_add_vertex(end, &mut graph, &mut id_to_name_map);
+        graph.add_edge(&id1, &id2).unwrap();
+    }
+
+    Ok((graph, id_to_name_map))
+}
+
+pub fn is_large(graph: &Graph<bool>, vertex: &VertexId) -> bool {
+    *graph.fetch(vertex).unwrap()
+}
+
+fn can_visit(graph: &Graph<bool>, path: &[VertexId], vertex: &VertexId) -> bool {
+    is_large(graph, vertex) || !path.contains(vertex)
+}
+
+fn is_path_allowed(graph: &Graph<bool>, path: &[VertexId]) -> bool {
+    let mut visit_counts: HashMap<VertexId, u32> = HashMap::new();
+    for v in path.iter() {
+        if is_large(graph, v) {
+            continue;
+        }
+        *visit_counts.entry(*v).or_insert(0) += 1;
+    }
+    visit_counts.values().max().unwrap() <= &2
+        && visit_counts.values().filter(|&&c| c == 2).count() <= 1
+}
+
+pub fn find_paths(graph: &Graph<bool>, &start: &VertexId, &end: &VertexId) -> Vec<Vec<VertexId>> {
+    let mut paths = Vec::new();
+    let mut queue = vec![vec![start]];
+
+    while let Some(path) = queue.pop() {
+        let path_end = path.last().unwrap();
+        if *path_end == end && !paths.contains(&path) {
+            paths.push(path);
+            continue;
+        }
+        for v in graph.neighbors(path_end) {
+            if !can_visit(graph, &path, v) {
+                continue;
+            }
+            let mut new_path = path.clone();
+            new_path.push(*v);
+            queue.push(new_path);
+        }
+    }
+
+    paths
+}
+
+pub fn find_paths_with_revisit(
+    graph: &Graph<bool>,
+    &start: &VertexId,
+    &end: &VertexId,
+    id_to_name_map: &BidirectionalMap<VertexId, String>,
+) -> Vec<Vec<VertexId>> {
+    let mut paths = Vec::new();
+    let mut queue = vec![vec![start]];
+
+    while let Some(path) = queue.pop() {
+        let path_end = path.last().unwrap();
+        if *path_end == end && !paths.contains(&path) {
+            trace!("{}", format_path(&path, id_to_name_map));
+            paths.push(path);
+            continue;
+        }
+        for v in graph.neighbors(path_end) {
+            if *v == start {
+                continue;
+            }
+            let mut new_path = path.clone();
+            new_path.push(*v);
+            if is_path_allowed(graph, &new_path) {
+                queue.push(new_path);
+            }
+        }
+    }
+
+    paths
+}
+
+pub fn format_path(
+    path: &[VertexId],
+    id_to_name_map: &BidirectionalMap<VertexId, String>,
+) -> String {
+    path.iter()
+        .map(|&v| id_to_name_map.get_value(v).unwrap().clone())
+        .collect::<Vec<String>>()
+        .join("-")
+}
+
+pub fn print_paths(paths: &[Vec<VertexId>], id_to_name_map: &BidirectionalMap<VertexId, String>) {
+    for path in paths.iter() {
+        println!("{}", format_path(path, id_to_name_map));
+    }
+}
+
+fn find_cave<'a>(
+    id_to_name_map: &'a BidirectionalMap<VertexId, String>,
+    name: &'static str,
+) -> Result<&'a VertexId, MissingCave> {
+    id_to_name_map
+        .get_key(name.to_string())
+        .ok_or(MissingCave(name))
+}
+
+pub fn part1((graph, id_to_name_map): &Caves) -> Result<usize, MissingCave> {
+    let start = find_cave(id_to_name_map, "start")?;
+    let end = find_cave(id_to_name_map, "end")?;
+    Ok(find_paths(graph, start, end).len())
+}
+
+pub fn part2((graph, id_to_name_map): &Caves) -> Result<usize, MissingCave> {
+    let start = find_cave(id_to_name_map, "start")?;
+    let end = find_cave(id_to_name_map, "end")?;
+    Ok(find_paths_with_revisit(graph, start, end, id_to_name_map).len())
+}
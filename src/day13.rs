@@ -0,0 +1,134 @@
+use crate::parse::{self, ParseError};
+use crate::scan::{self, Scanner};
+use std::collections::HashSet;
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum FoldType {
+    Horizontal,
+    Vertical,
+}
+
+/// A dot that lies further from a fold line than the fold can reach.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FoldError {
+    pub dot: (usize, usize),
+    pub fold: (FoldType, usize),
+}
+
+impl fmt::Display for FoldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let axis = match self.fold.0 {
+            FoldType::Vertical => 'x',
+            FoldType::Horizontal => 'y',
+        };
+        write!(
+            f,
+            "Dot at {},{} lies beyond the fold along {}={}",
+            self.dot.0, self.dot.1, axis, self.fold.1
+        )
+    }
+}
+
+impl std::error::Error for FoldError {}
+
+pub type Instructions = (HashSet<(usize, usize)>, Vec<(FoldType, usize)>);
+
+pub fn parse(input: &str) -> Result<Instructions, ParseError> {
+    let mut sections = scan::sections(input);
+    let mut points = HashSet::new();
+    if let Some((i, section)) = sections.next() {
+        let mut scanner = Scanner::starting_at(section, i);
+        while !scanner.is_done() {
+            let x = scanner.integer("integer")?;
+            scanner.expect(",")?;
+            let y = scanner.integer("integer")?;
+            if scanner.skip_whitespace() == 0 && !scanner.is_done() {
+                return Err(scanner.error("end of line"));
+            }
+            points.insert((x, y));
+        }
+    }
+
+    let mut folds = Vec::new();
+    if let Some((first, section)) = sections.next() {
+        for (i, line) in parse::lines(section) {
+            let i = first + i - 1;
+            let rest = parse::strip(i, line, line, "fold along ")?;
+            let [axis, loc] = parse::fields(i, line, rest, "=")?;
+            let dir = match axis {
+                "x" => FoldType::Vertical,
+                "y" => FoldType::Horizontal,
+                _ => return Err(ParseError::at(i, line, axis, "x or y")),
+            };
+            folds.push((dir, parse::token(i, line, loc, "integer")?));
+        }
+    }
+    if let Some((i, section)) = sections.next() {
+        return Err(ParseError::at(i, section, section, "end of input"));
+    }
+    if folds.is_empty() {
+        let line = input.lines().count() + 1;
+        return Err(ParseError::new(line, 1, "fold instruction", ""));
+    }
+
+    Ok((points, folds))
+}
+
+pub fn fold_points(
+    points: &HashSet<(usize, usize)>,
+    fold_dir: FoldType,
+    loc: usize,
+) -> Result<HashSet<(usize, usize)>, FoldError> {
+    let reflect = |c: usize| {
+        if c > loc {
+            loc.checked_sub(c - loc)
+        } else {
+            Some(c)
+        }
+    };
+    let mut new_points = HashSet::new();
+    for &(x, y) in points.iter() {
+        let folded = match fold_dir {
+            FoldType::Vertical => reflect(x).map(|x| (x, y)),
+            FoldType::Horizontal => reflect(y).map(|y| (x, y)),
+        };
+        new_points.insert(folded.ok_or(FoldError {
+            dot: (x, y),
+            fold: (fold_dir, loc),
+        })?);
+    }
+    Ok(new_points)
+}
+
+pub fn draw_points(points: &HashSet<(usize, usize)>) -> String {
+    let width = points.iter().map(|&(x, _)| x + 1).max().unwrap_or(0);
+    let height = points.iter().map(|&(_, y)| y + 1).max().unwrap_or(0);
+    let mut drawing = String::new();
+    for y in 0..height {
+        if y > 0 {
+            drawing.push('\n');
+        }
+        for x in 0..width {
+            if points.contains(&(x, y)) {
+                drawing.push('#');
+            } else {
+                drawing.push('.');
+            }
+        }
+    }
+    drawing
+}
+
+pub fn part1((points, folds): &Instructions) -> Result<usize, FoldError> {
+    let (fold_dir, loc) = folds[0];
+    Ok(fold_points(points, fold_dir, loc)?.len())
+}
+
+pub fn part2((points, folds): &Instructions) -> Result<String, FoldError> {
+    let mut points = points.clone();
+    for &(fold_dir, loc) in folds.iter() {
+        points = fold_points(&points, fold_dir, loc)?;
+    }
+    Ok(draw_points(&points))
+}
@@ -0,0 +1,82 @@
+use crate::parse::ParseError;
+use crate::scan;
+use std::collections::HashMap;
+
+pub type Rule = ((char, char), ((char, char), (char, char)));
+
+pub type Polymer = (HashMap<(char, char), u64>, Vec<Rule>);
+
+pub fn parse(input: &str) -> Result<Polymer, ParseError> {
+    let mut sections = scan::sections(input);
+    let (_, template) = sections
+        .next()
+        .ok_or_else(|| ParseError::new(1, 1, "polymer template", ""))?;
+    let mut pair_counts = HashMap::new();
+    for (c1, c2) in template.chars().zip(template.chars().skip(1)) {
+        *pair_counts.entry((c1, c2)).or_insert(0) += 1;
+    }
+    let mut rules = Vec::new();
+    if let Some((first, section)) = sections.next() {
+        for rule in scan::rules(section, first, " -> ")? {
+            let error = |token, expected| ParseError::at(rule.line, rule.text, token, expected);
+            let pattern_: (char, char) = match rule.key.chars().collect::<Vec<_>>()[..] {
+                [a, b] => (a, b),
+                _ => return Err(error(rule.key, "pair of elements")),
+            };
+            let insert = match rule.value.chars().collect::<Vec<_>>()[..] {
+                [c] => c,
+                _ => return Err(error(rule.value, "single element")),
+            };
+            rules.push((pattern_, ((pattern_.0, insert), (insert, pattern_.1))));
+        }
+    }
+
+    Ok((pair_counts, rules))
+}
+
+pub fn run_step(
+    pair_counts: &HashMap<(char, char), u64>,
+    rules: &[Rule],
+) -> HashMap<(char, char), u64> {
+    let mut pair_counts_new = pair_counts.clone();
+    for (pattern, insert) in rules.iter() {
+        if let Some(count) = pair_counts.get(pattern) {
+            *pair_counts_new.entry(*pattern).or_default() -= count;
+            *pair_counts_new.entry(insert.0).or_insert(0) += count;
+            *pair_counts_new.entry(insert.1).or_insert(0) += count;
+        }
+    }
+    pair_counts_new
+}
+
+pub fn calculate_score(pair_counts: &HashMap<(char, char), u64>) -> u64 {
+    let mut char_counts = HashMap::new();
+    for ((c1, c2), count) in pair_counts.iter() {
+        *char_counts.entry(c1).or_insert(0) += count;
+        *char_counts.entry(c2).or_insert(0) += count;
+    }
+    for count in char_counts.values_mut() {
+        *count = count.div_ceil(2);
+    }
+    // A one-element template has no pairs and never grows, so its single
+    // element is both the most and least common.
+    let max_count = char_counts.values().max().unwrap_or(&0);
+    let min_count = char_counts.values().min().unwrap_or(&0);
+    max_count - min_count
+}
+
+pub fn score_after_steps((pair_counts, rules): &Polymer, steps: u32) -> u64 {
+    let mut pair_counts = pair_counts.clone();
+    for _ in 0..steps {
+        pair_counts = run_step(&pair_counts, rules);
+    }
+    calculate_score(&pair_counts)
+}
+
+pub fn part1(input: &Polymer) -> u64 {
+    score_after_steps(input, 10)
+}
+
+pub fn part2(input: &Polymer) -> u64 {
+    score_after_steps(input, 40)
+}
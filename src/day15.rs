@@ -0,0 +1,41 @@
+use crate::grid::{Grid, Pos};
+use crate::parse::ParseError;
+use pathfinding::prelude::{absdiff, astar};
+
+/// Parses the risk levels, which are digits from 1 to 9. A risk of 0
+/// could not wrap around when the map is expanded.
+pub fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse_bytes_with(input, "digit from 1 to 9", |b| match b {
+        b'1'..=b'9' => Some(b - b'0'),
+        _ => None,
+    })
+}
+
+pub fn expand_map(map: &Grid<u8>) -> Grid<u8> {
+    let (height, width) = (map.height(), map.width());
+    Grid::from_fn(height * 5, width * 5, |(row, col)| {
+        let shift = (row / height + col / width) as u8;
+        (map[(row % height, col % width)] + shift - 1) % 9 + 1
+    })
+}
+
+pub fn get_shortest_path(map: &Grid<u8>) -> u32 {
+    let start = (0, 0);
+    let goal = (map.height() - 1, map.width() - 1);
+    astar(
+        &start,
+        |&pos| map.neighbors4(pos).map(|p| (p, map[p] as u32)),
+        |&(r, c): &Pos| (absdiff(r, goal.0) + absdiff(c, goal.1)) as u32,
+        |&p| p == goal,
+    )
+    .unwrap()
+    .1
+}
+
+pub fn part1(input: &Grid<u8>) -> u32 {
+    get_shortest_path(input)
+}
+
+pub fn part2(input: &Grid<u8>) -> u32 {
+    get_shortest_path(&expand_map(input))
+}
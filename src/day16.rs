@@ -0,0 +1,209 @@
+use crate::parse::{self, ParseError};
+use bitvec::prelude::*;
+use bitvec::slice::Iter;
+use hex::FromHex;
+use log::trace;
+use std::fmt;
+
+pub fn parse(input: &str) -> Result<BitVec<Msb0, u8>, ParseError> {
+    let line = parse::first_line(input, "hexadecimal transmission")?;
+    if let Some((i, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_hexdigit()) {
+        return Err(ParseError::at(
+            1,
+            line,
+            &line[i..i + c.len_utf8()],
+            "hexadecimal digit",
+        ));
+    }
+    match Vec::from_hex(line) {
+        Ok(bytes) => Ok(bytes.iter().collect()),
+        Err(_) => Err(ParseError::at(
+            1,
+            line,
+            &line[line.len()..],
+            "hexadecimal digit",
+        )),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PacketError {
+    /// A packet that runs past the end of the bits it was given.
+    Truncated,
+    /// A literal with more than 64 bits.
+    LiteralTooLong,
+    /// An operator packet of this type without operands.
+    NoOperands(u64),
+    /// A comparison packet of this type with this many operands instead of
+    /// two.
+    NotAPair(u64, usize),
+    /// An operator packet of this type whose value does not fit in a `u64`.
+    Overflow(u64),
+    /// A transmission without a single complete packet.
+    NoPackets,
+}
+
+impl fmt::Display for PacketError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PacketError::Truncated => write!(f, "Packet is cut off"),
+            PacketError::LiteralTooLong => write!(f, "Literal has more than 64 bits"),
+            PacketError::NoOperands(id) => write!(f, "Operator type {} has no operands", id),
+            PacketError::NotAPair(id, count) => {
+                write!(f, "Operator type {} has {} operands, not 2", id, count)
+            }
+            PacketError::Overflow(id) => write!(f, "Operator type {} overflows", id),
+            PacketError::NoPackets => write!(f, "Transmission has no complete packet"),
+        }
+    }
+}
+
+impl std::error::Error for PacketError {}
+
+fn bits_to_int(bits: &BitSlice<Msb0, u8>) -> u64 {
+    let mut v = 0;
+    v.view_bits_mut::<Msb0>()[64 - bits.len()..].clone_from_bitslice(bits);
+    v
+}
+
+fn take_bits(iter: &mut Iter<Msb0, u8>, bit_count: usize) -> Result<BitVec<Msb0, u8>, PacketError> {
+    let chunk: BitVec<Msb0, u8> = iter.take(bit_count).collect();
+    if chunk.len() < bit_count {
+        return Err(PacketError::Truncated);
+    }
+    Ok(chunk)
+}
+
+fn parse_int(iter: &mut Iter<Msb0, u8>, bit_count: usize) -> Result<u64, PacketError> {
+    let chunk = take_bits(iter, bit_count)?;
+    Ok(bits_to_int(chunk.as_bitslice()))
+}
+
+fn parse_literal(iter: &mut Iter<Msb0, u8>) -> Result<u64, PacketError> {
+    let mut bits: BitVec<Msb0, u8> = BitVec::new();
+    loop {
+        let chunk = take_bits(iter, 5)?;
+        bits.extend(chunk[1..].iter());
+        if bits.len() > 64 {
+            return Err(PacketError::LiteralTooLong);
+        }
+        if !chunk[0] {
+            break;
+        }
+    }
+    Ok(bits_to_int(bits.as_bitslice()))
+}
+
+/// Reads packets with `read_packet` until `bits` run out. A packet cut off
+/// at the end is padding and ignored.
+fn read_packets<T>(
+    bits: &BitVec<Msb0, u8>,
+    read_packet: impl Fn(&mut Iter<Msb0, u8>) -> Result<T, PacketError>,
+) -> Result<Vec<T>, PacketError> {
+    let mut iter = bits.iter();
+    let mut results = Vec::new();
+    while iter.len() > 0 {
+        match read_packet(&mut iter) {
+            Ok(res) => results.push(res),
+            Err(PacketError::Truncated) if iter.len() == 0 => break,
+            Err(error) => return Err(error),
+        }
+    }
+    Ok(results)
+}
+
+fn sum_operator_versions(iter: &mut Iter<Msb0, u8>) -> Result<u64, PacketError> {
+    let mut sum_version = 0;
+    let length_type = parse_int(iter, 1)?;
+    if length_type == 0 {
+        let num_bits = parse_int(iter, 15)?;
+        let bits = take_bits(iter, num_bits as usize)?;
+        sum_version += sum_versions(&bits)?;
+    } else {
+        let num_packets = parse_int(iter, 11)?;
+        for _ in 0..num_packets {
+            sum_version += sum_packet_versions(iter)?;
+        }
+    }
+    Ok(sum_version)
+}
+
+fn sum_packet_versions(iter: &mut Iter<Msb0, u8>) -> Result<u64, PacketError> {
+    let version = parse_int(iter, 3)?;
+    let mut sum_version = version;
+    let id = parse_int(iter, 3)?;
+    if id == 4 {
+        let val = parse_literal(iter)?;
+        trace!("Literal: {}", val);
+    } else {
+        trace!("Operator type {} begin", id);
+        sum_version += sum_operator_versions(iter)?;
+        trace!("Operator type {} end", id);
+    }
+    Ok(sum_version)
+}
+
+pub fn sum_versions(bits: &BitVec<Msb0, u8>) -> Result<u64, PacketError> {
+    Ok(read_packets(bits, sum_packet_versions)?.iter().sum())
+}
+
+fn evaluate_operands(iter: &mut Iter<Msb0, u8>) -> Result<Vec<u64>, PacketError> {
+    let length_type = parse_int(iter, 1)?;
+    if length_type == 0 {
+        let num_bits = parse_int(iter, 15)?;
+        let bits = take_bits(iter, num_bits as usize)?;
+        evaluate(&bits)
+    } else {
+        let num_packets = parse_int(iter, 11)?;
+        let mut vals = Vec::new();
+        for _ in 0..num_packets {
+            vals.push(evaluate_packet(iter)?);
+        }
+        Ok(vals)
+    }
+}
+
+fn evaluate_packet(iter: &mut Iter<Msb0, u8>) -> Result<u64, PacketError> {
+    let _version = parse_int(iter, 3)?;
+    let id = parse_int(iter, 3)?;
+    if id == 4 {
+        let val = parse_literal(iter)?;
+        trace!("Literal: {}", val);
+        return Ok(val);
+    }
+    trace!("Operator type {} begin", id);
+    let operands = evaluate_operands(iter)?;
+    trace!("Operator type {} end", id);
+    if operands.is_empty() {
+        return Err(PacketError::NoOperands(id));
+    }
+    let pair = || match operands[..] {
+        [a, b] => Ok((a, b)),
+        _ => Err(PacketError::NotAPair(id, operands.len())),
+    };
+    let value = match id {
+        0 => operands.iter().try_fold(0u64, |acc, &v| acc.checked_add(v)),
+        1 => operands.iter().try_fold(1u64, |acc, &v| acc.checked_mul(v)),
+        2 => operands.iter().min().copied(),
+        3 => operands.iter().max().copied(),
+        5 => pair().map(|(a, b)| Some(u64::from(a > b)))?,
+        6 => pair().map(|(a, b)| Some(u64::from(a < b)))?,
+        _ => pair().map(|(a, b)| Some(u64::from(a == b)))?,
+    };
+    value.ok_or(PacketError::Overflow(id))
+}
+
+pub fn evaluate(bits: &BitVec<Msb0, u8>) -> Result<Vec<u64>, PacketError> {
+    read_packets(bits, evaluate_packet)
+}
+
+pub fn part1(input: &BitVec<Msb0, u8>) -> Result<u64, PacketError> {
+    sum_versions(input)
+}
+
+pub fn part2(input: &BitVec<Msb0, u8>) -> Result<u64, PacketError> {
+    evaluate(input)?
+        .first()
+        .copied()
+        .ok_or(PacketError::NoPackets)
+}
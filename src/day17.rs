@@ -0,0 +1,90 @@
+use crate::parse::{self, ParseError};
+use ndarray::prelude::*;
+use std::cmp::max;
+
+pub struct Target {
+    pub x_min: i32,
+    pub x_max: i32,
+    pub y_min: i32,
+    pub y_max: i32,
+}
+
+fn parse_range(line: &str, text: &str, axis: &str) -> Result<(i32, i32), ParseError> {
+    let range = parse::strip(1, line, text, axis)?;
+    let [a, b] = parse::fields(1, line, range, "..")?;
+    Ok((
+        parse::token(1, line, a, "integer")?,
+        parse::token(1, line, b, "integer")?,
+    ))
+}
+
+pub fn parse(input: &str) -> Result<Target, ParseError> {
+    let line = parse::first_line(input, "target area")?;
+    let rest = parse::strip(1, line, line, "target area: ")?;
+    let [x, y] = parse::fields(1, line, rest, ", ")?;
+    let (x_min, x_max) = parse_range(line, x, "x=")?;
+    let (y_min, y_max) = parse_range(line, y, "y=")?;
+    Ok(Target {
+        x_min,
+        x_max,
+        y_min,
+        y_max,
+    })
+}
+
+pub fn simulate_trajectory(start_vel: Array1<i32>, target: &Target) -> Option<i32> {
+    let mut pos = arr1(&[0, 0]);
+    let mut vel = start_vel;
+    let mut max_y = 0;
+    loop {
+        pos += &vel;
+        if vel[0] > 0 {
+            vel[0] -= 1;
+        }
+        vel[1] -= 1;
+        max_y = max(max_y, pos[1]);
+        if target.x_min <= pos[0]
+            && pos[0] <= target.x_max
+            && target.y_min <= pos[1]
+            && pos[1] <= target.y_max
+        {
+            return Some(max_y);
+        }
+        if pos[0] > target.x_max || pos[1] < target.y_min && vel[1] <= 0 {
+            return None;
+        }
+    }
+}
+
+pub fn find_max_y(target: &Target) -> i32 {
+    let mut max_y = 0;
+    for vel_y in target.y_min..target.x_max {
+        for vel_x in 1..target.x_max {
+            max_y = max(
+                max_y,
+                simulate_trajectory(arr1(&[vel_x, vel_y]), target).unwrap_or(0),
+            );
+        }
+    }
+    max_y
+}
+
+pub fn find_num_options(target: &Target) -> u32 {
+    let mut num_options = 0;
+    for vel_y in target.y_min..=2 * target.x_max {
+        for vel_x in 1..=target.x_max {
+            if simulate_trajectory(arr1(&[vel_x, vel_y]), target).is_some() {
+                num_options += 1;
+            }
+        }
+    }
+    num_options
+}
+
+pub fn part1(input: &Target) -> i32 {
+    find_max_y(input)
+}
+
+pub fn part2(input: &Target) -> u32 {
+    find_num_options(input)
+}
@@ -0,0 +1,214 @@
+use crate::parse::{self, ParseError};
+use std::fmt;
+use std::iter::Peekable;
+use std::ops::Add;
+use std::str::{CharIndices, FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pair {
+    a: Option<Box<Pair>>,
+    b: Option<Box<Pair>>,
+    val: Option<u32>,
+}
+
+impl Pair {
+    pub fn leaf(val: u32) -> Pair {
+        Pair {
+            a: None,
+            b: None,
+            val: Some(val),
+        }
+    }
+
+    pub fn node(a: Pair, b: Pair) -> Pair {
+        Pair {
+            a: Some(Box::new(a)),
+            b: Some(Box::new(b)),
+            val: None,
+        }
+    }
+
+    pub fn is_leaf(&self) -> bool {
+        self.val.is_some()
+    }
+
+    pub fn get_left(&self) -> &Pair {
+        self.a.as_ref().unwrap()
+    }
+
+    pub fn get_right(&self) -> &Pair {
+        self.b.as_ref().unwrap()
+    }
+
+    fn get_left_mut(&mut self) -> &mut Pair {
+        self.a.as_mut().unwrap()
+    }
+
+    fn get_right_mut(&mut self) -> &mut Pair {
+        self.b.as_mut().unwrap()
+    }
+
+    pub fn get_val(&self) -> u32 {
+        self.val.unwrap()
+    }
+
+    fn add_leftmost(&mut self, val: u32) {
+        match self.val.as_mut() {
+            Some(v) => *v += val,
+            None => self.get_left_mut().add_leftmost(val),
+        }
+    }
+
+    fn add_rightmost(&mut self, val: u32) {
+        match self.val.as_mut() {
+            Some(v) => *v += val,
+            None => self.get_right_mut().add_rightmost(val),
+        }
+    }
+
+    fn explode(&mut self, depth: usize) -> Option<(u32, u32)> {
+        if self.is_leaf() {
+            return None;
+        }
+        if depth >= 4 && self.get_left().is_leaf() && self.get_right().is_leaf() {
+            let carry = (self.get_left().get_val(), self.get_right().get_val());
+            *self = Pair::leaf(0);
+            return Some(carry);
+        }
+        if let Some((left, right)) = self.get_left_mut().explode(depth + 1) {
+            self.get_right_mut().add_leftmost(right);
+            return Some((left, 0));
+        }
+        if let Some((left, right)) = self.get_right_mut().explode(depth + 1) {
+            self.get_left_mut().add_rightmost(left);
+            return Some((0, right));
+        }
+        None
+    }
+
+    fn split(&mut self) -> bool {
+        match self.val {
+            Some(val) if val >= 10 => {
+                *self = Pair::node(Pair::leaf(val / 2), Pair::leaf(val.div_ceil(2)));
+                true
+            }
+            Some(_) => false,
+            None => self.get_left_mut().split() || self.get_right_mut().split(),
+        }
+    }
+
+    pub fn reduce(&mut self) {
+        while self.explode(0).is_some() || self.split() {}
+    }
+
+    pub fn magnitude(&self) -> u32 {
+        if self.is_leaf() {
+            self.get_val()
+        } else {
+            self.get_left().magnitude() * 3 + self.get_right().magnitude() * 2
+        }
+    }
+}
+
+impl Add for Pair {
+    type Output = Pair;
+
+    fn add(self, other: Pair) -> Pair {
+        let mut sum = Pair::node(self, other);
+        sum.reduce();
+        sum
+    }
+}
+
+impl fmt::Display for Pair {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_leaf() {
+            write!(f, "{}", self.get_val())
+        } else {
+            write!(f, "[{},{}]", self.get_left(), self.get_right())
+        }
+    }
+}
+
+fn error_at(s: &str, chars: &mut Peekable<CharIndices>, expected: &str) -> ParseError {
+    match chars.peek() {
+        Some(&(i, c)) => ParseError::at(1, s, &s[i..i + c.len_utf8()], expected),
+        None => ParseError::at(1, s, &s[s.len()..], expected),
+    }
+}
+
+fn expect(s: &str, chars: &mut Peekable<CharIndices>, c: char) -> Result<(), ParseError> {
+    match chars.peek() {
+        Some(&(_, next)) if next == c => {
+            chars.next();
+            Ok(())
+        }
+        _ => Err(error_at(s, chars, &format!("{:?}", c))),
+    }
+}
+
+fn parse_pair(s: &str, chars: &mut Peekable<CharIndices>) -> Result<Pair, ParseError> {
+    match chars.peek() {
+        Some((_, '[')) => {
+            chars.next();
+            let a = parse_pair(s, chars)?;
+            expect(s, chars, ',')?;
+            let b = parse_pair(s, chars)?;
+            expect(s, chars, ']')?;
+            Ok(Pair::node(a, b))
+        }
+        Some((_, c)) if c.is_ascii_digit() => {
+            let mut val = 0;
+            while let Some(d) = chars.peek().and_then(|(_, c)| c.to_digit(10)) {
+                val = val * 10 + d;
+                chars.next();
+            }
+            Ok(Pair::leaf(val))
+        }
+        _ => Err(error_at(s, chars, "'[' or number")),
+    }
+}
+
+impl FromStr for Pair {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Pair, ParseError> {
+        let mut chars = s.char_indices().peekable();
+        let pair = parse_pair(s, &mut chars)?;
+        if chars.peek().is_some() {
+            return Err(error_at(s, &mut chars, "end of line"));
+        }
+        Ok(pair)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Pair>, ParseError> {
+    parse::lines(input)
+        .map(|(i, line)| {
+            line.parse()
+                .map_err(|e: ParseError| e.within(i, line, line))
+        })
+        .collect()
+}
+
+pub fn part1(input: &[Pair]) -> u32 {
+    input
+        .iter()
+        .cloned()
+        .reduce(|a, b| a + b)
+        .unwrap()
+        .magnitude()
+}
+
+pub fn part2(input: &[Pair]) -> u32 {
+    let mut max_magnitude = 0;
+    for (i, p1) in input.iter().enumerate() {
+        for (j, p2) in input.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+            max_magnitude = max_magnitude.max((p1.clone() + p2.clone()).magnitude());
+        }
+    }
+    max_magnitude
+}
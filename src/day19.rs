@@ -0,0 +1,168 @@
+use crate::parse::ParseError;
+use crate::scan::{self, Scanner};
+use crate::vec3::{Rotation, Vec3};
+use log::debug;
+use num::Zero;
+use rand::distributions::Uniform;
+use rand::prelude::IteratorRandom;
+use rand::Rng;
+use std::cmp::max;
+use std::collections::HashSet;
+use std::fmt;
+
+pub type Point = Vec3<i32>;
+
+/// A round guesses a rotation and a beacon pair for every two scans. Scans
+/// of `n` and `m` beacons that share 12 are matched with odds of about
+/// 1 in `2 * n * m` per round, so `match_scans` gives up after
+/// `PATIENCE * n * m` rounds in a row without a match, for the two largest
+/// scans. A match that exists is then missed about once in e^20 times.
+pub const PATIENCE: usize = 40;
+
+/// The number of scans still apart when `match_scans` gave up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unmatched(pub usize);
+
+impl fmt::Display for Unmatched {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} scans share no 12 beacons with each other", self.0)
+    }
+}
+
+impl std::error::Error for Unmatched {}
+
+pub fn parse(input: &str) -> Result<Vec<HashSet<Point>>, ParseError> {
+    let mut scans = Vec::new();
+    for (i, section) in scan::sections(input) {
+        let mut scanner = Scanner::starting_at(section, i);
+        let header = scanner.rest_of_line();
+        if !header.starts_with("---") {
+            return Err(ParseError::at(i, header, header, "scanner header"));
+        }
+        let mut scan = HashSet::new();
+        while !scanner.is_done() {
+            let x = scanner.integer("integer")?;
+            scanner.expect(",")?;
+            let y = scanner.integer("integer")?;
+            scanner.expect(",")?;
+            let z = scanner.integer("integer")?;
+            if scanner.skip_whitespace() == 0 && !scanner.is_done() {
+                return Err(scanner.error("end of line"));
+            }
+            scan.insert(Point::new(x, y, z));
+        }
+        if scan.is_empty() {
+            return Err(ParseError::new(i + 1, 1, "beacon position", ""));
+        }
+        scans.push(scan);
+    }
+    if scans.is_empty() {
+        return Err(ParseError::new(1, 1, "scanner header", ""));
+    }
+
+    Ok(scans)
+}
+
+/// Where a scanner is relative to scanner 0, and the rotation that turns its
+/// readings into scanner 0's orientation.
+pub type Placement = (Point, Rotation);
+
+/// Merges all scans into `scans[0]` and returns the placement of every
+/// scanner, in input order. Gives up once matching has gone on for long
+/// enough that the scans left almost certainly share too few beacons.
+pub fn match_scans(scans: &mut Vec<HashSet<Point>>) -> Result<Vec<Placement>, Unmatched> {
+    let mut rng = rand::thread_rng();
+    let rotations = Rotation::all();
+    let rotation_dist = Uniform::from(0..rotations.len());
+    // The scanners merged into each scan, placed relative to that scan.
+    let mut placements = (0..scans.len())
+        .map(|k| vec![(k, (Point::zero(), Rotation::IDENTITY))])
+        .collect::<Vec<_>>();
+    let mut rounds = 0;
+    'outer: while scans.len() > 1 {
+        let mut sizes = scans.iter().map(|s| s.len()).collect::<Vec<_>>();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        if rounds == PATIENCE * sizes[0] * sizes[1] {
+            return Err(Unmatched(scans.len()));
+        }
+        rounds += 1;
+        let index = rng.sample(rotation_dist);
+        let rotation = &rotations[index];
+        for i in 0..scans.len() {
+            let &p1 = scans[i].iter().choose(&mut rng).unwrap();
+            for j in i + 1..scans.len() {
+                let p2 = rotation.apply(*scans[j].iter().choose(&mut rng).unwrap());
+                let offset = p1 - p2;
+                let count = count_matches(&scans[i], &scans[j], rotation, offset);
+                if count >= 12 {
+                    let scan_upd = scans
+                        .remove(j)
+                        .iter()
+                        .map(|&p| rotation.apply(p) + offset)
+                        .collect::<HashSet<_>>();
+                    scans[i].extend(scan_upd);
+                    let placement_upd = placements
+                        .remove(j)
+                        .into_iter()
+                        .map(|(k, (position, turn))| {
+                            (
+                                k,
+                                (rotation.apply(position) + offset, rotation.compose(&turn)),
+                            )
+                        })
+                        .collect::<Vec<_>>();
+                    placements[i].extend(placement_upd);
+                    debug!(
+                        "Match {}:{}, rotation {}, offset {:?}, {} left",
+                        i,
+                        j,
+                        index,
+                        offset,
+                        scans.len() - 1
+                    );
+                    rounds = 0;
+                    continue 'outer;
+                }
+            }
+        }
+    }
+    let mut placements = placements.remove(0);
+    placements.sort_by_key(|&(k, _)| k);
+    Ok(placements
+        .into_iter()
+        .map(|(_, placement)| placement)
+        .collect())
+}
+
+pub fn count_matches(
+    scan1: &HashSet<Point>,
+    scan2: &HashSet<Point>,
+    rotation: &Rotation,
+    offset: Point,
+) -> u32 {
+    let mut count = 0;
+    for &p in scan2 {
+        if scan1.contains(&(rotation.apply(p) + offset)) {
+            count += 1;
+        }
+    }
+    count
+}
+
+pub fn part1(input: &[HashSet<Point>]) -> Result<usize, Unmatched> {
+    let mut scans = input.to_vec();
+    match_scans(&mut scans)?;
+    Ok(scans[0].len())
+}
+
+pub fn part2(input: &[HashSet<Point>]) -> Result<u32, Unmatched> {
+    let mut scans = input.to_vec();
+    let placements = match_scans(&mut scans)?;
+    let mut max_dist = 0;
+    for &(p1, _) in placements.iter() {
+        for &(p2, _) in placements.iter() {
+            max_dist = max(max_dist, p1.manhattan_to(&p2) as u32);
+        }
+    }
+    Ok(max_dist)
+}
@@ -0,0 +1,110 @@
+use crate::parse::{self, ParseError};
+use strum_macros::EnumString;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString)]
+#[strum(serialize_all = "lowercase")]
+pub enum Direction {
+    Up,
+    Down,
+    Forward,
+}
+
+pub type Command = (Direction, i64);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Position {
+    pub horizontal: i64,
+    pub depth: i64,
+}
+
+/// What a command does to the submarine. Models can keep their own state,
+/// such as the aim.
+pub trait Movement {
+    fn apply(&mut self, position: Position, command: Command) -> Position;
+}
+
+/// `up` and `down` change the depth directly.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Plain;
+
+impl Movement for Plain {
+    fn apply(&mut self, mut position: Position, (direction, amount): Command) -> Position {
+        match direction {
+            Direction::Up => position.depth -= amount,
+            Direction::Down => position.depth += amount,
+            Direction::Forward => position.horizontal += amount,
+        }
+        position
+    }
+}
+
+/// `up` and `down` change the aim, which sets how deep `forward` goes.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Aim {
+    pub aim: i64,
+}
+
+impl Movement for Aim {
+    fn apply(&mut self, mut position: Position, (direction, amount): Command) -> Position {
+        match direction {
+            Direction::Up => self.aim -= amount,
+            Direction::Down => self.aim += amount,
+            Direction::Forward => {
+                position.horizontal += amount;
+                position.depth += self.aim * amount;
+            }
+        }
+        position
+    }
+}
+
+/// Every position the submarine passes through, starting at the origin.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trajectory {
+    pub positions: Vec<Position>,
+}
+
+impl Trajectory {
+    pub fn end(&self) -> Position {
+        *self.positions.last().unwrap()
+    }
+}
+
+fn parse_command(i: usize, line: &str) -> Result<Command, ParseError> {
+    let [a, b] = parse::fields(i, line, line, " ")?;
+    Ok((
+        parse::token(i, line, a, "up, down or forward")?,
+        parse::token(i, line, b, "integer")?,
+    ))
+}
+
+pub fn parse(input: &str) -> Result<Vec<Command>, ParseError> {
+    parse::lines(input)
+        .map(|(i, line)| parse_command(i, line))
+        .collect()
+}
+
+pub fn run(model: &mut impl Movement, commands: &[Command]) -> Trajectory {
+    let mut positions = vec![Position::default()];
+    for &command in commands.iter() {
+        let position = *positions.last().unwrap();
+        positions.push(model.apply(position, command));
+    }
+    Trajectory { positions }
+}
+
+/// Parses the commands and runs them. If a line is not a command, returns
+/// the error for the first such line without running any command.
+pub fn interpret(model: &mut impl Movement, input: &str) -> Result<Trajectory, ParseError> {
+    parse(input).map(|commands| run(model, &commands))
+}
+
+pub fn part1(input: &[Command]) -> i64 {
+    let end = run(&mut Plain, input).end();
+    end.horizontal * end.depth
+}
+
+pub fn part2(input: &[Command]) -> i64 {
+    let end = run(&mut Aim::default(), input).end();
+    end.horizontal * end.depth
+}
@@ -0,0 +1,110 @@
+use crate::grid::Grid;
+use crate::parse::{self, ParseError};
+use std::fmt;
+
+/// An infinite image: the pixels in `pixels` plus `background` everywhere
+/// else.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pixels: Grid<bool>,
+    background: bool,
+}
+
+impl Image {
+    pub fn new(pixels: Grid<bool>) -> Image {
+        Image {
+            pixels,
+            background: false,
+        }
+    }
+
+    pub fn get(&self, row: isize, col: isize) -> bool {
+        *self.pixels.get_signed(row, col).unwrap_or(&self.background)
+    }
+
+    /// Number of lit pixels. Infinite when the background is lit, in which
+    /// case only the finite part is counted.
+    pub fn len(&self) -> usize {
+        self.pixels.iter().filter(|&&p| p).count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn get_window(&self, row: isize, col: isize) -> usize {
+        let mut value = 0;
+        for r in row - 1..=row + 1 {
+            for c in col - 1..=col + 1 {
+                value = value << 1 | self.get(r, c) as usize;
+            }
+        }
+        value
+    }
+}
+
+impl fmt::Display for Image {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.pixels.map(|&p| if p { '#' } else { '.' }))
+    }
+}
+
+fn pixel(c: char) -> Option<bool> {
+    match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    }
+}
+
+pub fn parse(input: &str) -> Result<(Vec<bool>, Image), ParseError> {
+    let line = parse::first_line(input, "enhancement algorithm")?;
+    if line.chars().count() != 512 {
+        return Err(ParseError::at(1, line, line, "512 pixels"));
+    }
+    let algo = Grid::parse_with(line, "'#' or '.'", pixel)?;
+
+    let image_start = input
+        .match_indices('\n')
+        .nth(1)
+        .map_or(input.len(), |(i, _)| i + 1);
+    let pixels =
+        Grid::parse_with(&input[image_start..], "'#' or '.'", pixel).map_err(|mut e| {
+            e.line += 2;
+            e
+        })?;
+
+    Ok((algo.iter().copied().collect(), Image::new(pixels)))
+}
+
+pub fn print_image(image: &Image) {
+    println!("{}", image);
+}
+
+/// Applies `algo` once. The image grows by one pixel on every side, since
+/// pixels further out only see the background.
+pub fn enhance_image(image: &Image, algo: &[bool]) -> Image {
+    let pixels = Grid::from_fn(
+        image.pixels.height() + 2,
+        image.pixels.width() + 2,
+        |(row, col)| algo[image.get_window(row as isize - 1, col as isize - 1)],
+    );
+    let background = algo[if image.background { 511 } else { 0 }];
+    Image { pixels, background }
+}
+
+pub fn count_after_enhancing((algo, image): &(Vec<bool>, Image), rounds: u32) -> usize {
+    let mut image = image.clone();
+    for _ in 0..rounds {
+        image = enhance_image(&image, algo);
+    }
+    image.len()
+}
+
+pub fn part1(input: &(Vec<bool>, Image)) -> usize {
+    count_after_enhancing(input, 2)
+}
+
+pub fn part2(input: &(Vec<bool>, Image)) -> usize {
+    count_after_enhancing(input, 50)
+}
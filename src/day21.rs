@@ -0,0 +1,138 @@
+use crate::parse::{self, ParseError};
+use log::trace;
+use std::cmp::max;
+use std::collections::HashMap;
+
+pub fn parse(input: &str) -> Result<(u8, u8), ParseError> {
+    let mut positions = [0; 2];
+    let mut lines = parse::lines(input);
+    for (player, pos) in positions.iter_mut().enumerate() {
+        let prefix = format!("Player {} starting position: ", player + 1);
+        let (i, line) = lines
+            .next()
+            .ok_or_else(|| ParseError::new(player + 1, 1, &format!("{:?}", prefix), ""))?;
+        let value = parse::strip(i, line, line, &prefix)?;
+        *pos = match parse::token(i, line, value, "position from 1 to 10")? {
+            p @ 1..=10 => p,
+            _ => return Err(ParseError::at(i, line, value, "position from 1 to 10")),
+        };
+    }
+    Ok((positions[0], positions[1]))
+}
+
+pub fn increment_pos(pos: u8, amount: u8) -> u8 {
+    let mut pos_new = pos;
+    pos_new += amount;
+    if pos_new > 10 {
+        pos_new = (pos_new - 1) % 10 + 1;
+    }
+    pos_new
+}
+
+fn increment_dice(dice: &mut u8) {
+    *dice += 1;
+    if *dice > 100 {
+        *dice = 1;
+    }
+}
+
+pub fn simulate_deterministic_game(start_pos: (u8, u8)) -> u32 {
+    let mut pos = [start_pos.0, start_pos.1];
+    let mut scores = vec![0u32; pos.len()];
+    let mut dice_counter = 1;
+    let mut num_rolls = 0u32;
+    'main: loop {
+        for (p, s) in pos.iter_mut().zip(scores.iter_mut()) {
+            for _ in 0..3 {
+                *p = increment_pos(*p, dice_counter);
+                increment_dice(&mut dice_counter);
+                num_rolls += 1;
+            }
+            *s += *p as u32;
+            if *s >= 1000 {
+                break 'main;
+            }
+        }
+    }
+    num_rolls * scores.iter().min().unwrap()
+}
+
+fn simulate_roll(pos: u8, count: u64) -> HashMap<u8, u64> {
+    let mut player_pos_counts = HashMap::from([(pos, count)]);
+    for _ in 0..3 {
+        let mut player_pos_counts_new = HashMap::new();
+        for dice_roll in 1..=3 {
+            for (&p_, &c_) in player_pos_counts.iter() {
+                let p_new = increment_pos(p_, dice_roll);
+                *player_pos_counts_new.entry(p_new).or_insert(0) += c_;
+            }
+        }
+        player_pos_counts = player_pos_counts_new;
+    }
+    player_pos_counts
+}
+
+fn update_score(pos_counts: &HashMap<u8, u64>, score: u8) -> HashMap<(u8, u8), u64> {
+    let mut pos_score_counts = HashMap::new();
+    for (&p_, &c_) in pos_counts.iter() {
+        *pos_score_counts.entry((p_, score + p_)).or_insert(0) += c_;
+    }
+    pos_score_counts
+}
+
+fn remove_wins(pos_score_counts: &mut HashMap<(u8, u8), u64>) -> u64 {
+    let mut count = 0;
+    pos_score_counts.retain(|&(_, s), &mut c| {
+        if s >= 21 {
+            count += c;
+            false
+        } else {
+            true
+        }
+    });
+    count
+}
+
+pub fn simulate_dirac_game(start_pos: (u8, u8)) -> u64 {
+    let mut score_pos_counts = HashMap::from([((start_pos, (0u8, 0u8)), 1u64)]);
+    let mut num_p1_wins = 0;
+    let mut num_p2_wins = 0;
+    while !score_pos_counts.is_empty() {
+        let mut score_pos_counts_new = HashMap::new();
+        for (&((p1, p2), (s1, s2)), &c) in score_pos_counts.iter() {
+            if c == 0 {
+                continue;
+            }
+            let player1_pos_counts = simulate_roll(p1, c);
+            let mut player1_pos_score_counts = update_score(&player1_pos_counts, s1);
+            num_p1_wins += remove_wins(&mut player1_pos_score_counts);
+            for (&(p1_new, s1_new), &c1_new) in player1_pos_score_counts.iter() {
+                let player2_pos_counts = simulate_roll(p2, c1_new);
+                let mut player2_pos_score_counts = update_score(&player2_pos_counts, s2);
+                num_p2_wins += remove_wins(&mut player2_pos_score_counts);
+                for (&(p2_new, s2_new), &c_new) in player2_pos_score_counts.iter() {
+                    *score_pos_counts_new
+                        .entry(((p1_new, p2_new), (s1_new, s2_new)))
+                        .or_insert(0) += c_new;
+                }
+            }
+        }
+        score_pos_counts = score_pos_counts_new;
+        trace!(
+            "{} states, {} universes, {} vs {} wins",
+            score_pos_counts.len(),
+            score_pos_counts.values().sum::<u64>(),
+            num_p1_wins,
+            num_p2_wins
+        );
+    }
+    max(num_p1_wins, num_p2_wins)
+}
+
+pub fn part1(input: &(u8, u8)) -> u32 {
+    simulate_deterministic_game(*input)
+}
+
+pub fn part2(input: &(u8, u8)) -> u64 {
+    simulate_dirac_game(*input)
+}
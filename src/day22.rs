@@ -0,0 +1,164 @@
+use crate::parse::{self, ParseError};
+use crate::vec3::Vec3;
+use ndarray::prelude::*;
+use num::Integer;
+use std::cmp::{max, min};
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub struct Range<T: Copy + Integer> {
+    pub min: T,
+    pub max: T,
+}
+
+impl<T: Copy + Integer> Range<T> {
+    pub fn contains(&self, p: T) -> bool {
+        p >= self.min && p < self.max
+    }
+
+    pub fn crop(&mut self, other: &Range<T>) {
+        self.min = max(self.min, other.min);
+        self.max = min(self.max, other.max);
+    }
+
+    pub fn size(&self) -> T {
+        if self.max > self.min {
+            self.max - self.min
+        } else {
+            T::zero()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Cuboid<T: Copy + Integer> {
+    pub ranges: Vec3<Range<T>>,
+    pub value: bool,
+}
+
+impl<T: Copy + Integer> Cuboid<T> {
+    pub fn contains(&self, p: Vec3<T>) -> bool {
+        (0..3).all(|axis| self.ranges[axis].contains(p[axis]))
+    }
+
+    pub fn crop(&mut self, bounds: &Vec3<Range<T>>) {
+        for axis in 0..3 {
+            self.ranges[axis].crop(&bounds[axis]);
+        }
+    }
+
+    pub fn size(&self) -> T {
+        (0..3).fold(T::one(), |size, axis| size * self.ranges[axis].size())
+    }
+}
+
+fn parse_range(i: usize, line: &str, text: &str, axis: &str) -> Result<Range<i64>, ParseError> {
+    let range = parse::strip(i, line, text, axis)?;
+    let [a, b] = parse::fields(i, line, range, "..")?;
+    Ok(Range {
+        min: parse::token(i, line, a, "integer")?,
+        max: parse::token::<i64>(i, line, b, "integer")? + 1,
+    })
+}
+
+pub fn parse(input: &str) -> Result<Vec<Cuboid<i64>>, ParseError> {
+    let mut regions = Vec::new();
+    for (i, line) in parse::lines(input) {
+        let [val, rest] = parse::fields(i, line, line, " ")?;
+        let value = match val {
+            "on" => true,
+            "off" => false,
+            _ => return Err(ParseError::at(i, line, val, "on or off")),
+        };
+        let [x, y, z] = parse::fields(i, line, rest, ",")?;
+        regions.push(Cuboid {
+            ranges: Vec3::new(
+                parse_range(i, line, x, "x=")?,
+                parse_range(i, line, y, "y=")?,
+                parse_range(i, line, z, "z=")?,
+            ),
+            value,
+        });
+    }
+    Ok(regions)
+}
+
+fn compress_axis<T: Copy + Integer + Hash>(
+    all_values: HashSet<T>,
+) -> (HashMap<usize, T>, HashMap<T, usize>) {
+    let mut values_sorted = all_values.into_iter().collect::<Vec<_>>();
+    values_sorted.sort();
+    let mut id_to_value = HashMap::new();
+    let mut value_to_id = HashMap::new();
+    for (i, v) in values_sorted.iter().enumerate() {
+        id_to_value.insert(i, *v);
+        value_to_id.insert(*v, i);
+    }
+    (id_to_value, value_to_id)
+}
+
+pub fn count_on(regions: &[Cuboid<i64>]) -> u64 {
+    let axes: [_; 3] = std::array::from_fn(|axis| {
+        compress_axis(
+            regions
+                .iter()
+                .flat_map(|r| [r.ranges[axis].min, r.ranges[axis].max])
+                .collect(),
+        )
+    });
+
+    let regions_coded = regions
+        .iter()
+        .map(|r| Cuboid {
+            ranges: Vec3::from_array(std::array::from_fn(|axis| {
+                let (_, value_to_id) = &axes[axis];
+                Range {
+                    min: value_to_id[&r.ranges[axis].min],
+                    max: value_to_id[&r.ranges[axis].max],
+                }
+            })),
+            value: r.value,
+        })
+        .collect::<Vec<_>>();
+
+    let mut map = Array3::from_elem((axes[0].1.len(), axes[1].1.len(), axes[2].1.len()), false);
+
+    for region in regions_coded.iter() {
+        if region.size() == 0 {
+            continue;
+        }
+        let [x, y, z] = region.ranges.to_array();
+        map.slice_mut(s![x.min..x.max, y.min..y.max, z.min..z.max])
+            .fill(region.value);
+    }
+
+    map.indexed_iter()
+        .filter(|(_, &on)| on)
+        .map(|((x, y, z), _)| {
+            let block_size = [x, y, z]
+                .iter()
+                .zip(axes.iter())
+                .map(|(&i, (id_to_value, _))| id_to_value[&(i + 1)] - id_to_value[&i])
+                .product::<i64>();
+            block_size as u64
+        })
+        .sum()
+}
+
+pub fn part1(input: &[Cuboid<i64>]) -> u64 {
+    let regions = input
+        .iter()
+        .map(|r| {
+            let mut region = *r;
+            let bounds = Range { min: -50, max: 51 };
+            region.crop(&Vec3::new(bounds, bounds, bounds));
+            region
+        })
+        .collect::<Vec<_>>();
+    count_on(&regions)
+}
+
+pub fn part2(input: &[Cuboid<i64>]) -> u64 {
+    count_on(input)
+}
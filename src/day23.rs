@@ -0,0 +1,564 @@
+use crate::parse::{self, ParseError};
+use log::{debug, trace};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use strum_macros::{Display, EnumString};
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum AmphipodType {
+    A,
+    B,
+    C,
+    D,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum AmphipodState {
+    NotStarted,
+    Active,
+    Locked,
+    Final,
+    Done,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Amphipod {
+    class: AmphipodType,
+    state: AmphipodState,
+}
+
+impl Amphipod {
+    pub fn new(class: char) -> Amphipod {
+        Amphipod {
+            class: match class {
+                'A' => AmphipodType::A,
+                'B' => AmphipodType::B,
+                'C' => AmphipodType::C,
+                'D' => AmphipodType::D,
+                _ => panic!(),
+            },
+            state: AmphipodState::NotStarted,
+        }
+    }
+
+    fn activate(&mut self) {
+        self.state = match self.state {
+            AmphipodState::NotStarted | AmphipodState::Active => AmphipodState::Active,
+            AmphipodState::Locked | AmphipodState::Final => AmphipodState::Final,
+            _ => panic!(),
+        };
+    }
+
+    fn deactivate(&mut self, loc: Loc) -> bool {
+        match (self.state, loc) {
+            (_, x) if x == self.destination() => {
+                self.state = AmphipodState::Done;
+                true
+            }
+            (AmphipodState::Active, Loc::Hallway(_)) => {
+                self.state = AmphipodState::Locked;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        match self.class {
+            AmphipodType::A => "A",
+            AmphipodType::B => "B",
+            AmphipodType::C => "C",
+            AmphipodType::D => "D",
+        }
+    }
+
+    fn weight(&self) -> u64 {
+        match self.class {
+            AmphipodType::A => 1,
+            AmphipodType::B => 10,
+            AmphipodType::C => 100,
+            AmphipodType::D => 1000,
+        }
+    }
+
+    fn destination(&self) -> Loc {
+        match self.class {
+            AmphipodType::A => Loc::Room(RoomLoc::A),
+            AmphipodType::B => Loc::Room(RoomLoc::B),
+            AmphipodType::C => Loc::Room(RoomLoc::C),
+            AmphipodType::D => Loc::Room(RoomLoc::D),
+        }
+    }
+
+    fn can_enter(&self, r: Loc) -> bool {
+        match r {
+            Loc::Hallway(_) => true,
+            x => x == self.destination(),
+        }
+    }
+}
+
+#[derive(Debug, Display, EnumString, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum HallwayLoc {
+    LeftLeft,
+    Left,
+    MidLeft,
+    Mid,
+    MidRight,
+    Right,
+    RightRight,
+}
+
+#[derive(Debug, Display, EnumString, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum RoomLoc {
+    A,
+    B,
+    C,
+    D,
+}
+
+#[derive(Debug, Display, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum Loc {
+    Hallway(HallwayLoc),
+    Room(RoomLoc),
+}
+
+const HALLWAY: [HallwayLoc; 7] = [
+    HallwayLoc::LeftLeft,
+    HallwayLoc::Left,
+    HallwayLoc::MidLeft,
+    HallwayLoc::Mid,
+    HallwayLoc::MidRight,
+    HallwayLoc::Right,
+    HallwayLoc::RightRight,
+];
+
+const ROOMS: [RoomLoc; 4] = [RoomLoc::A, RoomLoc::B, RoomLoc::C, RoomLoc::D];
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Room {
+    capacity: usize,
+    content: [Option<Amphipod>; 4],
+    location: Loc,
+}
+
+impl Room {
+    fn single(content: Option<Amphipod>, location: Loc) -> Room {
+        Room {
+            capacity: 1,
+            content: [content, None, None, None],
+            location,
+        }
+    }
+
+    fn stack(content: &[Amphipod], location: Loc) -> Room {
+        let mut room = Room {
+            capacity: content.len(),
+            content: [None, None, None, None],
+            location,
+        };
+        for (slot, amphipod) in room.content.iter_mut().zip(content.iter()) {
+            *slot = Some(amphipod.clone());
+        }
+        room
+    }
+
+    fn has_space(&self) -> bool {
+        self.content[self.capacity - 1].is_none()
+    }
+
+    fn as_vec(&self) -> Vec<Option<Amphipod>> {
+        self.content[..self.capacity].to_vec()
+    }
+
+    fn is_solved(&self) -> bool {
+        let all_at_dest = self
+            .as_vec()
+            .iter()
+            .all(|a| a.as_ref().map(|a| a.destination()) == Some(self.location));
+        match self.location {
+            Loc::Room(_) => all_at_dest,
+            _ => self.outer().is_none(),
+        }
+    }
+
+    fn outer(&self) -> Option<usize> {
+        (0..self.capacity)
+            .rev()
+            .find(|&i| self.content[i].is_some())
+    }
+
+    fn next(&self) -> Option<usize> {
+        match self.outer() {
+            Some(outer) if outer >= self.capacity - 1 => None,
+            Some(outer) => Some(outer + 1),
+            None => Some(0),
+        }
+    }
+
+    fn removal_distance(&self) -> u64 {
+        match self.location {
+            Loc::Hallway(HallwayLoc::LeftLeft) | Loc::Hallway(HallwayLoc::RightRight) => 0,
+            _ => (self.capacity - self.outer().unwrap()) as u64,
+        }
+    }
+
+    fn insertion_distance(&self) -> u64 {
+        match self.location {
+            Loc::Hallway(HallwayLoc::LeftLeft) | Loc::Hallway(HallwayLoc::RightRight) => 0,
+            _ => (self.capacity - self.next().unwrap()) as u64,
+        }
+    }
+
+    fn remove(&mut self) -> Amphipod {
+        let idx = self.outer().unwrap();
+        self.content[idx].take().unwrap()
+    }
+
+    fn insert(&mut self, a: Amphipod) {
+        self.content[self.next().unwrap()] = Some(a);
+    }
+
+    fn is_dirty(&self) -> bool {
+        self.as_vec()
+            .iter()
+            .flatten()
+            .any(|a| a.destination() != self.location)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Map {
+    rooms: HashMap<Loc, Room>,
+    connections: HashMap<Loc, HashSet<Loc>>,
+}
+
+impl Map {
+    /// Creates a map with an empty hallway. Each side room is listed from the
+    /// bottom up.
+    pub fn new(side_rooms: [Vec<Amphipod>; 4]) -> Map {
+        let mut rooms = HashMap::new();
+        for hallway_loc in HALLWAY {
+            rooms.insert(
+                Loc::Hallway(hallway_loc),
+                Room::single(None, Loc::Hallway(hallway_loc)),
+            );
+        }
+        for (room_loc, content) in ROOMS.into_iter().zip(side_rooms.iter()) {
+            rooms.insert(
+                Loc::Room(room_loc),
+                Room::stack(content, Loc::Room(room_loc)),
+            );
+        }
+
+        let mut connections = HashMap::new();
+        for room in rooms.keys() {
+            connections.insert(*room, HashSet::new());
+        }
+        connections
+            .get_mut(&Loc::Hallway(HallwayLoc::LeftLeft))
+            .unwrap()
+            .extend([Loc::Hallway(HallwayLoc::Left)]);
+        connections
+            .get_mut(&Loc::Hallway(HallwayLoc::Left))
+            .unwrap()
+            .extend([Loc::Room(RoomLoc::A), Loc::Hallway(HallwayLoc::MidLeft)]);
+        connections
+            .get_mut(&Loc::Hallway(HallwayLoc::MidLeft))
+            .unwrap()
+            .extend([
+                Loc::Room(RoomLoc::A),
+                Loc::Room(RoomLoc::B),
+                Loc::Hallway(HallwayLoc::Mid),
+            ]);
+        connections
+            .get_mut(&Loc::Hallway(HallwayLoc::Mid))
+            .unwrap()
+            .extend([
+                Loc::Room(RoomLoc::B),
+                Loc::Room(RoomLoc::C),
+                Loc::Hallway(HallwayLoc::MidRight),
+            ]);
+        connections
+            .get_mut(&Loc::Hallway(HallwayLoc::MidRight))
+            .unwrap()
+            .extend([
+                Loc::Room(RoomLoc::C),
+                Loc::Room(RoomLoc::D),
+                Loc::Hallway(HallwayLoc::Right),
+            ]);
+        connections
+            .get_mut(&Loc::Hallway(HallwayLoc::Right))
+            .unwrap()
+            .extend([Loc::Room(RoomLoc::D), Loc::Hallway(HallwayLoc::RightRight)]);
+        for (&room1, neighbors) in connections.clone().iter() {
+            for room2 in neighbors.iter() {
+                connections.get_mut(room2).unwrap().insert(room1);
+            }
+        }
+
+        Map { rooms, connections }
+    }
+
+    /// Inserts the two extra rows of the full diagram between the top and
+    /// bottom row of every side room.
+    pub fn unfold(&self) -> Map {
+        let extra = [['D', 'D'], ['B', 'C'], ['A', 'B'], ['C', 'A']];
+        let side_rooms = ROOMS.map(|room_loc| {
+            let room = &self.rooms[&Loc::Room(room_loc)];
+            let [lower, upper] = extra[room_loc as usize];
+            vec![
+                room.content[0].clone().unwrap(),
+                Amphipod::new(lower),
+                Amphipod::new(upper),
+                room.content[1].clone().unwrap(),
+            ]
+        });
+        Map::new(side_rooms)
+    }
+
+    fn depth(&self) -> usize {
+        self.rooms[&Loc::Room(RoomLoc::A)].capacity
+    }
+
+    fn get_moves(&self) -> Vec<(Loc, Loc)> {
+        let mut moves = vec![];
+        for (&room_loc, room) in self.rooms.iter() {
+            let outer = match room.outer() {
+                Some(x) => x,
+                None => continue,
+            };
+            let amphipod = room.content[outer].as_ref().unwrap();
+            if amphipod.state == AmphipodState::Done {
+                continue;
+            }
+            for &neighbor_loc in self.connections[&room_loc].iter() {
+                let neighbor = &self.rooms[&neighbor_loc];
+                if !amphipod.can_enter(neighbor_loc) || !neighbor.has_space() {
+                    continue;
+                }
+                if neighbor_loc == amphipod.destination() && neighbor.is_dirty() {
+                    continue;
+                }
+                moves.push((room_loc, neighbor_loc));
+            }
+        }
+        moves
+    }
+
+    pub fn execute_move(&mut self, src_loc: Loc, dst_loc: Loc) -> u64 {
+        if !self.get_moves().contains(&(src_loc, dst_loc)) {
+            panic!(
+                "Invalid move: {:?}->{:?}, valid moves: {:?}",
+                src_loc,
+                dst_loc,
+                self.get_moves()
+            );
+        }
+        let src = self.rooms.get_mut(&src_loc).unwrap();
+        let mut cost = src.removal_distance();
+        let amph = src.remove();
+        let dst = self.rooms.get_mut(&dst_loc).unwrap();
+        cost += dst.insertion_distance();
+        cost *= amph.weight();
+        dst.insert(amph);
+        cost
+    }
+
+    pub fn execute_moves(&mut self, steps: &[Loc]) -> u64 {
+        let mut cost = 0;
+        self.activate(*steps.first().unwrap());
+        for (&src, &dst) in steps[..steps.len() - 1].iter().zip(steps[1..].iter()) {
+            cost += self.execute_move(src, dst);
+        }
+        if !self.deactivate(*steps.last().unwrap()) {
+            panic!("Invalid move sequence: {:?}", steps);
+        }
+        cost
+    }
+
+    pub fn is_solved(&self) -> bool {
+        self.rooms.values().all(|r| r.is_solved())
+    }
+
+    fn fmt_amph(&self, x: Loc, y: usize) -> &str {
+        match &self.rooms.get(&x).unwrap().content[y] {
+            Some(x) => x.as_str(),
+            None => ".",
+        }
+    }
+
+    fn get_state(&self) -> [Option<Amphipod>; 23] {
+        [
+            self.rooms[&Loc::Hallway(HallwayLoc::LeftLeft)].content[0].clone(),
+            self.rooms[&Loc::Hallway(HallwayLoc::Left)].content[0].clone(),
+            self.rooms[&Loc::Hallway(HallwayLoc::MidLeft)].content[0].clone(),
+            self.rooms[&Loc::Hallway(HallwayLoc::Mid)].content[0].clone(),
+            self.rooms[&Loc::Hallway(HallwayLoc::MidRight)].content[0].clone(),
+            self.rooms[&Loc::Hallway(HallwayLoc::Right)].content[0].clone(),
+            self.rooms[&Loc::Hallway(HallwayLoc::RightRight)].content[0].clone(),
+            self.rooms[&Loc::Room(RoomLoc::A)].content[3].clone(),
+            self.rooms[&Loc::Room(RoomLoc::B)].content[3].clone(),
+            self.rooms[&Loc::Room(RoomLoc::C)].content[3].clone(),
+            self.rooms[&Loc::Room(RoomLoc::D)].content[3].clone(),
+            self.rooms[&Loc::Room(RoomLoc::A)].content[2].clone(),
+            self.rooms[&Loc::Room(RoomLoc::B)].content[2].clone(),
+            self.rooms[&Loc::Room(RoomLoc::C)].content[2].clone(),
+            self.rooms[&Loc::Room(RoomLoc::D)].content[2].clone(),
+            self.rooms[&Loc::Room(RoomLoc::A)].content[1].clone(),
+            self.rooms[&Loc::Room(RoomLoc::B)].content[1].clone(),
+            self.rooms[&Loc::Room(RoomLoc::C)].content[1].clone(),
+            self.rooms[&Loc::Room(RoomLoc::D)].content[1].clone(),
+            self.rooms[&Loc::Room(RoomLoc::A)].content[0].clone(),
+            self.rooms[&Loc::Room(RoomLoc::B)].content[0].clone(),
+            self.rooms[&Loc::Room(RoomLoc::C)].content[0].clone(),
+            self.rooms[&Loc::Room(RoomLoc::D)].content[0].clone(),
+        ]
+    }
+
+    fn activate(&mut self, loc: Loc) {
+        let room = self.rooms.get_mut(&loc).unwrap();
+        let idx = room.outer().unwrap();
+        room.content[idx].as_mut().unwrap().activate();
+    }
+
+    fn deactivate(&mut self, loc: Loc) -> bool {
+        let room = self.rooms.get_mut(&loc).unwrap();
+        let idx = room.outer().unwrap();
+        room.content[idx].as_mut().unwrap().deactivate(loc)
+    }
+}
+
+impl fmt::Display for Map {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "#############")?;
+        writeln!(
+            f,
+            "#{}{}.{}.{}.{}.{}{}#",
+            self.fmt_amph(Loc::Hallway(HallwayLoc::LeftLeft), 0),
+            self.fmt_amph(Loc::Hallway(HallwayLoc::Left), 0),
+            self.fmt_amph(Loc::Hallway(HallwayLoc::MidLeft), 0),
+            self.fmt_amph(Loc::Hallway(HallwayLoc::Mid), 0),
+            self.fmt_amph(Loc::Hallway(HallwayLoc::MidRight), 0),
+            self.fmt_amph(Loc::Hallway(HallwayLoc::Right), 0),
+            self.fmt_amph(Loc::Hallway(HallwayLoc::RightRight), 0),
+        )?;
+        for y in (0..self.depth()).rev() {
+            writeln!(
+                f,
+                "###{}#{}#{}#{}###",
+                self.fmt_amph(Loc::Room(RoomLoc::A), y),
+                self.fmt_amph(Loc::Room(RoomLoc::B), y),
+                self.fmt_amph(Loc::Room(RoomLoc::C), y),
+                self.fmt_amph(Loc::Room(RoomLoc::D), y),
+            )?;
+        }
+        write!(f, "#############")
+    }
+}
+
+type Moves = Vec<Vec<Loc>>;
+
+fn solve_inner(
+    map: &Map,
+    cost_so_far: u64,
+    seen_states: &mut HashMap<[Option<Amphipod>; 23], u64>,
+    previous: Moves,
+    best: &mut Option<(u64, Moves)>,
+) {
+    if map.is_solved() {
+        if best.is_none() || cost_so_far < best.as_ref().unwrap().0 {
+            debug!("New best: {}", cost_so_far);
+            *best = Some((cost_so_far, previous));
+        }
+        return;
+    }
+    let last = previous.last().and_then(|x| x.last());
+    for (src, dst) in map.get_moves() {
+        let mut new_map = map.clone();
+        if last != Some(&src) {
+            if let Some(&last) = last {
+                if !new_map.deactivate(last) {
+                    continue;
+                }
+            }
+            new_map.activate(src);
+        }
+        let new_cost = cost_so_far + new_map.execute_move(src, dst);
+        if best.is_some() && new_cost >= best.as_ref().unwrap().0 {
+            continue;
+        }
+        let new_state = new_map.get_state();
+        match seen_states.get(&new_state) {
+            Some(&seen_cost) if seen_cost <= new_cost => continue,
+            Some(_) => {}
+            None if seen_states.len().is_multiple_of(1000) => trace!("Seen {}", seen_states.len()),
+            None => {}
+        }
+        seen_states.insert(new_state, new_cost);
+        let mut moves = previous.clone();
+        if last == Some(&src) {
+            moves.last_mut().unwrap().push(dst);
+        } else {
+            moves.push(vec![src, dst]);
+        }
+        solve_inner(&new_map, new_cost, seen_states, moves, best);
+    }
+}
+
+pub fn solve_initial_guess(map: &Map, initial_guess: Option<u64>) -> (u64, Moves) {
+    let mut seen_states = HashMap::new();
+    seen_states.insert(map.get_state(), 0);
+    let mut best = initial_guess.map(|guess| (guess, vec![]));
+    solve_inner(map, 0, &mut seen_states, vec![], &mut best);
+    best.unwrap()
+}
+
+pub fn solve(map: &Map) -> (u64, Moves) {
+    solve_initial_guess(map, None)
+}
+
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    let mut rows = Vec::new();
+    let mut last_line = 0;
+    for (i, line) in parse::lines(input).skip(2) {
+        last_line = i;
+        let cells = line
+            .split('#')
+            .map(str::trim)
+            .filter(|cell| !cell.is_empty())
+            .collect::<Vec<_>>();
+        if cells.is_empty() {
+            continue;
+        }
+        if cells.len() != 4 {
+            return Err(ParseError::at(i, line, line, "row of 4 amphipods"));
+        }
+        let mut row = Vec::new();
+        for cell in cells {
+            match cell {
+                "A" | "B" | "C" | "D" => row.push(Amphipod::new(cell.chars().next().unwrap())),
+                _ => return Err(ParseError::at(i, line, cell, "amphipod A-D")),
+            }
+        }
+        rows.push(row);
+    }
+    if rows.is_empty() || rows.len() > 4 {
+        return Err(ParseError::new(
+            last_line + 1,
+            1,
+            "1 to 4 rows of amphipods",
+            "",
+        ));
+    }
+    let side_rooms = [0, 1, 2, 3].map(|i| rows.iter().rev().map(|row| row[i].clone()).collect());
+    Ok(Map::new(side_rooms))
+}
+
+pub fn part1(input: &Map) -> u64 {
+    solve(input).0
+}
+
+pub fn part2(input: &Map) -> u64 {
+    solve(&input.unfold()).0
+}
@@ -0,0 +1,202 @@
+use crate::parse::{self, ParseError};
+use std::fmt;
+
+/// Binary numbers that all have the same number of digits.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub width: usize,
+    pub numbers: Vec<u64>,
+}
+
+pub fn parse(input: &str) -> Result<Report, ParseError> {
+    let mut width = None;
+    let mut numbers = Vec::new();
+    for (i, line) in parse::lines(input) {
+        let width = *width.get_or_insert(line.len());
+        if line.is_empty() || line.len() > 64 || line.len() != width {
+            let expected = match width {
+                1..=64 => format!("{}-bit binary number", width),
+                _ => "binary number of 1 to 64 bits".to_string(),
+            };
+            return Err(ParseError::at(i, line, line, &expected));
+        }
+        if let Some(j) = line.bytes().position(|b| b != b'0' && b != b'1') {
+            let c = line[j..].chars().next().unwrap();
+            return Err(ParseError::at(
+                i,
+                line,
+                &line[j..j + c.len_utf8()],
+                "binary digit",
+            ));
+        }
+        numbers.push(u64::from_str_radix(line, 2).unwrap());
+    }
+    Ok(Report {
+        width: width.unwrap_or(0),
+        numbers,
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rating {
+    OxygenGenerator,
+    Co2Scrubber,
+}
+
+/// Why bit criteria did not narrow the report down to a single number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RatingError {
+    /// The criteria removed every number at this bit.
+    NoneLeft(Rating, usize),
+    /// Several equal numbers, or none at all, were left after the last bit.
+    Ambiguous(Rating, usize),
+}
+
+impl fmt::Display for RatingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = |rating: &Rating| match rating {
+            Rating::OxygenGenerator => "oxygen generator rating",
+            Rating::Co2Scrubber => "CO2 scrubber rating",
+        };
+        match self {
+            RatingError::NoneLeft(rating, bit) => {
+                write!(
+                    f,
+                    "No number is left for the {} at bit {}",
+                    name(rating),
+                    bit
+                )
+            }
+            RatingError::Ambiguous(rating, count) => {
+                write!(f, "{} numbers are left for the {}", count, name(rating))
+            }
+        }
+    }
+}
+
+impl std::error::Error for RatingError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Diagnostics {
+    pub gamma: u64,
+    pub epsilon: u64,
+    pub oxygen_generator: Result<u64, RatingError>,
+    pub co2_scrubber: Result<u64, RatingError>,
+}
+
+/// For each bit, a bitset of the numbers that have that bit set.
+fn bit_columns(report: &Report) -> Vec<Vec<u64>> {
+    let words = report.numbers.len().div_ceil(64);
+    let mut columns = vec![vec![0u64; words]; report.width];
+    for (i, &number) in report.numbers.iter().enumerate() {
+        for (bit, column) in columns.iter_mut().enumerate() {
+            column[i / 64] |= (number >> bit & 1) << (i % 64);
+        }
+    }
+    columns
+}
+
+fn count(set: &[u64]) -> usize {
+    set.iter().map(|word| word.count_ones() as usize).sum()
+}
+
+/// Narrows `candidates` down with the bit criteria, given the column of
+/// the current bit.
+struct Filter {
+    rating: Rating,
+    candidates: Vec<u64>,
+    result: Option<Result<usize, RatingError>>,
+}
+
+impl Filter {
+    fn new(rating: Rating, len: usize) -> Filter {
+        let mut candidates = vec![u64::MAX; len.div_ceil(64)];
+        if !len.is_multiple_of(64) {
+            *candidates.last_mut().unwrap() = (1 << (len % 64)) - 1;
+        }
+        Filter {
+            rating,
+            candidates,
+            result: None,
+        }
+    }
+
+    fn single(&self) -> Option<usize> {
+        let (i, word) = self
+            .candidates
+            .iter()
+            .enumerate()
+            .find(|(_, &word)| word != 0)?;
+        Some(i * 64 + word.trailing_zeros() as usize)
+    }
+
+    fn step(&mut self, bit: usize, column: &[u64]) {
+        if self.result.is_some() {
+            return;
+        }
+        let total = count(&self.candidates);
+        if total == 1 {
+            self.result = self.single().map(Ok);
+            return;
+        }
+        let ones = self
+            .candidates
+            .iter()
+            .zip(column)
+            .map(|(c, b)| (c & b).count_ones() as usize)
+            .sum::<usize>();
+        let keep_ones = (2 * ones >= total) == (self.rating == Rating::OxygenGenerator);
+        for (c, b) in self.candidates.iter_mut().zip(column) {
+            *c &= if keep_ones { *b } else { !*b };
+        }
+        if self.candidates.iter().all(|&word| word == 0) {
+            self.result = Some(Err(RatingError::NoneLeft(self.rating, bit)));
+        }
+    }
+
+    fn finish(self) -> Result<usize, RatingError> {
+        match self.result {
+            Some(result) => result,
+            None => match count(&self.candidates) {
+                1 => Ok(self.single().unwrap()),
+                n => Err(RatingError::Ambiguous(self.rating, n)),
+            },
+        }
+    }
+}
+
+/// Computes all diagnostics in one pass over the bit columns, from the most
+/// significant bit down. On ties gamma takes a zero, the oxygen generator
+/// rating keeps ones and the CO2 scrubber rating keeps zeros.
+pub fn analyze(report: &Report) -> Diagnostics {
+    let len = report.numbers.len();
+    let columns = bit_columns(report);
+    let (mut gamma, mut epsilon) = (0, 0);
+    let mut oxygen = Filter::new(Rating::OxygenGenerator, len);
+    let mut co2 = Filter::new(Rating::Co2Scrubber, len);
+    for (bit, column) in columns.iter().enumerate().rev() {
+        if 2 * count(column) > len {
+            gamma |= 1 << bit;
+        } else {
+            epsilon |= 1 << bit;
+        }
+        oxygen.step(bit, column);
+        co2.step(bit, column);
+    }
+    Diagnostics {
+        gamma,
+        epsilon,
+        oxygen_generator: oxygen.finish().map(|i| report.numbers[i]),
+        co2_scrubber: co2.finish().map(|i| report.numbers[i]),
+    }
+}
+
+pub fn part1(input: &Report) -> u128 {
+    let diagnostics = analyze(input);
+    diagnostics.gamma as u128 * diagnostics.epsilon as u128
+}
+
+pub fn part2(input: &Report) -> Result<u128, RatingError> {
+    let diagnostics = analyze(input);
+    Ok(diagnostics.oxygen_generator? as u128 * diagnostics.co2_scrubber? as u128)
+}
@@ -0,0 +1,332 @@
+use crate::parse::ParseError;
+use crate::scan;
+use colored::*;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Which lines of marked numbers win.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Rules {
+    pub diagonals: bool,
+}
+
+/// An NxN bingo board that keeps count of the marks in every row, column
+/// and diagonal, so marking a number and checking for a win are O(1).
+#[derive(Debug, Clone)]
+pub struct BingoBoard {
+    size: usize,
+    numbers: Vec<u32>,
+    hits: Vec<bool>,
+    row_hits: Vec<usize>,
+    col_hits: Vec<usize>,
+    diag_hits: [usize; 2],
+    number_index: HashMap<u32, Vec<usize>>,
+}
+
+impl BingoBoard {
+    /// A board with `numbers` in row-major order, or `None` if they do not
+    /// fill a square.
+    pub fn new(numbers: Vec<u32>) -> Option<BingoBoard> {
+        let size = (1..=numbers.len()).find(|size| size * size >= numbers.len())?;
+        if size * size != numbers.len() {
+            return None;
+        }
+        let mut number_index: HashMap<u32, Vec<usize>> = HashMap::new();
+        for (i, &num) in numbers.iter().enumerate() {
+            number_index.entry(num).or_default().push(i);
+        }
+        Some(BingoBoard {
+            size,
+            hits: vec![false; numbers.len()],
+            numbers,
+            row_hits: vec![0; size],
+            col_hits: vec![0; size],
+            diag_hits: [0; 2],
+            number_index,
+        })
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn number(&self, row: usize, col: usize) -> u32 {
+        self.numbers[row * self.size + col]
+    }
+
+    pub fn is_marked(&self, row: usize, col: usize) -> bool {
+        self.hits[row * self.size + col]
+    }
+
+    /// Marks every cell with `num`, returning whether that completed a
+    /// winning line.
+    pub fn mark_number(&mut self, num: u32, rules: Rules) -> bool {
+        let mut won = false;
+        for &i in self.number_index.get(&num).into_iter().flatten() {
+            if self.hits[i] {
+                continue;
+            }
+            self.hits[i] = true;
+            let (row, col) = (i / self.size, i % self.size);
+            self.row_hits[row] += 1;
+            self.col_hits[col] += 1;
+            won |= self.row_hits[row] == self.size || self.col_hits[col] == self.size;
+            if row == col {
+                self.diag_hits[0] += 1;
+                won |= rules.diagonals && self.diag_hits[0] == self.size;
+            }
+            if row + col + 1 == self.size {
+                self.diag_hits[1] += 1;
+                won |= rules.diagonals && self.diag_hits[1] == self.size;
+            }
+        }
+        won
+    }
+
+    pub fn won(&self, rules: Rules) -> bool {
+        self.row_hits.contains(&self.size)
+            || self.col_hits.contains(&self.size)
+            || (rules.diagonals && self.diag_hits.contains(&self.size))
+    }
+
+    /// The sum of the unmarked numbers.
+    pub fn score(&self) -> u64 {
+        self.numbers
+            .iter()
+            .zip(self.hits.iter())
+            .filter(|(_, &hit)| !hit)
+            .map(|(&num, _)| num as u64)
+            .sum()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Bingo {
+    pub draws: Vec<u32>,
+    pub boards: Vec<BingoBoard>,
+}
+
+fn parse_board(first: usize, section: &str) -> Result<BingoBoard, ParseError> {
+    let mut rows: Vec<Vec<u32>> = Vec::new();
+    for (i, line) in section.lines().enumerate() {
+        let line_no = first + i;
+        let row = scan::integers(line, line_no, b" ", "number")?;
+        let size = rows.first().map_or(row.len(), |first| first.len());
+        if rows.len() == size {
+            return Err(ParseError::at(line_no, line, line, "end of board"));
+        }
+        if row.len() != size {
+            let expected = format!("row of {} numbers", size);
+            return Err(ParseError::at(line_no, line, line, &expected));
+        }
+        rows.push(row);
+    }
+    let size = rows[0].len();
+    if rows.len() != size {
+        let expected = format!("row of {} numbers", size);
+        return Err(ParseError::new(first + rows.len(), 1, &expected, ""));
+    }
+    Ok(BingoBoard::new(rows.concat()).unwrap())
+}
+
+pub fn parse(input: &str) -> Result<Bingo, ParseError> {
+    let mut sections = scan::sections(input);
+    let (i, line) = sections
+        .next()
+        .ok_or_else(|| ParseError::new(1, 1, "list of draws", ""))?;
+    let draws = scan::integers(line, i, b",", "number")?;
+
+    let mut boards = vec![];
+    for (i, section) in sections {
+        boards.push(parse_board(i, section)?);
+    }
+
+    Ok(Bingo { draws, boards })
+}
+
+/// A board completing a line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Win {
+    pub board: usize,
+    /// The index of the winning draw.
+    pub turn: usize,
+    pub draw: u32,
+    pub score: u64,
+}
+
+/// The order in which boards win, from one simulation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ranking {
+    /// Wins by turn, and by board on the same turn.
+    pub wins: Vec<Win>,
+    /// Boards that never won.
+    pub losers: Vec<usize>,
+}
+
+impl Ranking {
+    pub fn first(&self) -> Option<&Win> {
+        self.wins.first()
+    }
+
+    pub fn last(&self) -> Option<&Win> {
+        self.wins.last()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoWinner;
+
+impl fmt::Display for NoWinner {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "No board wins")
+    }
+}
+
+impl std::error::Error for NoWinner {}
+
+impl Bingo {
+    /// Draws numbers until every board has won or the draws run out.
+    pub fn play(&self, rules: Rules) -> Ranking {
+        let mut replay = Replay::new(self, rules);
+        let mut wins = Vec::new();
+        while wins.len() < self.boards.len() {
+            match replay.step() {
+                Some(new_wins) => wins.extend(new_wins),
+                None => break,
+            }
+        }
+        let losers = (0..self.boards.len())
+            .filter(|&board| replay.wins[board].is_none())
+            .collect();
+        Ranking { wins, losers }
+    }
+}
+
+/// Steps through the draws of a game, keeping the state of every board so
+/// it can be inspected or rendered at any draw.
+#[derive(Debug, Clone)]
+pub struct Replay<'a> {
+    bingo: &'a Bingo,
+    rules: Rules,
+    boards: Vec<BingoBoard>,
+    wins: Vec<Option<Win>>,
+    turn: usize,
+}
+
+impl<'a> Replay<'a> {
+    pub fn new(bingo: &'a Bingo, rules: Rules) -> Replay<'a> {
+        Replay {
+            bingo,
+            rules,
+            boards: bingo.boards.clone(),
+            wins: vec![None; bingo.boards.len()],
+            turn: 0,
+        }
+    }
+
+    /// The number of draws made so far.
+    pub fn turn(&self) -> usize {
+        self.turn
+    }
+
+    pub fn boards(&self) -> &[BingoBoard] {
+        &self.boards
+    }
+
+    /// The win of each board, if it has won yet.
+    pub fn wins(&self) -> &[Option<Win>] {
+        &self.wins
+    }
+
+    /// Makes the next draw, returning the boards that won with it, or
+    /// `None` if there are no draws left.
+    pub fn step(&mut self) -> Option<Vec<Win>> {
+        let turn = self.turn;
+        let &draw = self.bingo.draws.get(turn)?;
+        self.turn += 1;
+        let mut new_wins = Vec::new();
+        for (board, (state, win)) in self.boards.iter_mut().zip(self.wins.iter_mut()).enumerate() {
+            if state.mark_number(draw, self.rules) && win.is_none() {
+                *win = Some(Win {
+                    board,
+                    turn,
+                    draw,
+                    score: draw as u64 * state.score(),
+                });
+                new_wins.extend(*win);
+            }
+        }
+        Some(new_wins)
+    }
+
+    /// Moves to the state after `turn` draws, or after the last draw if
+    /// there are fewer. Going back replays the game from the start.
+    pub fn seek(&mut self, turn: usize) {
+        if turn < self.turn {
+            *self = Replay::new(self.bingo, self.rules);
+        }
+        while self.turn < turn && self.step().is_some() {}
+    }
+
+    /// Every board with its marked cells highlighted, the cells of the last
+    /// draw highlighted differently, and a note on the boards that have won.
+    pub fn render(&self) -> String {
+        let last = self.turn.checked_sub(1).map(|turn| self.bingo.draws[turn]);
+        let mut out = match last {
+            Some(draw) => format!("Draw {}/{}: {}\n", self.turn, self.bingo.draws.len(), draw),
+            None => format!("Draw 0/{}\n", self.bingo.draws.len()),
+        };
+        let width = self
+            .boards
+            .iter()
+            .flat_map(|board| board.numbers.iter())
+            .map(|num| num.to_string().len())
+            .max()
+            .unwrap_or(0);
+        for (i, (board, win)) in self.boards.iter().zip(self.wins.iter()).enumerate() {
+            out += &format!("\nBoard {}", i + 1);
+            if let Some(win) = win {
+                let note = format!(
+                    " won on draw {} with {}, score {}",
+                    win.turn + 1,
+                    win.draw,
+                    win.score
+                );
+                if win.turn + 1 == self.turn {
+                    out += &format!("{}", note.yellow().bold());
+                } else {
+                    out += &format!("{}", note.yellow());
+                }
+            }
+            out += "\n";
+            for row in 0..board.size {
+                let cells: Vec<String> = (0..board.size)
+                    .map(|col| {
+                        let num = board.number(row, col);
+                        let cell = format!("{:>width$}", num, width = width);
+                        if !board.is_marked(row, col) {
+                            cell
+                        } else if Some(num) == last {
+                            cell.black().on_yellow().to_string()
+                        } else {
+                            cell.green().bold().to_string()
+                        }
+                    })
+                    .collect();
+                out += &cells.join(" ");
+                out += "\n";
+            }
+        }
+        out
+    }
+}
+
+pub fn part1(input: &Bingo) -> Result<u64, NoWinner> {
+    let ranking = input.play(Rules::default());
+    ranking.first().map(|win| win.score).ok_or(NoWinner)
+}
+
+pub fn part2(input: &Bingo) -> Result<u64, NoWinner> {
+    let ranking = input.play(Rules::default());
+    ranking.last().map(|win| win.score).ok_or(NoWinner)
+}
@@ -0,0 +1,722 @@
+use crate::parse::{self, ParseError};
+use num::rational::Ratio;
+use num::Integer;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap, HashMap};
+use std::ops;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl FromStr for Point {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Point, ParseError> {
+        let [x, y] = parse::fields(1, s, s, ",")?;
+        Ok(Point {
+            x: parse::token(1, s, x.trim(), "integer")?,
+            y: parse::token(1, s, y.trim(), "integer")?,
+        })
+    }
+}
+
+impl ops::Add<Point> for Point {
+    type Output = Point;
+
+    fn add(self, _rhs: Point) -> Point {
+        Point {
+            x: self.x + _rhs.x,
+            y: self.y + _rhs.y,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Line {
+    pub start: Point,
+    pub end: Point,
+}
+
+impl FromStr for Line {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Line, ParseError> {
+        let [start, end] = parse::fields(1, s, s, "->")?;
+        let (start, end) = (start.trim(), end.trim());
+        Ok(Line {
+            start: start
+                .parse()
+                .map_err(|e: ParseError| e.within(1, s, start))?,
+            end: end.parse().map_err(|e: ParseError| e.within(1, s, end))?,
+        })
+    }
+}
+
+/// Lines that are not horizontal, vertical or diagonal must stay within
+/// this distance of the origin, so that intersections fit in an `i128`.
+pub const MAX_SLANTED: i64 = 1 << 31;
+
+/// The direction of a line as the smallest step between its lattice
+/// points, pointing right, or up for vertical lines. Lines with the same
+/// direction are told apart by `key`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Direction {
+    pub dx: i128,
+    pub dy: i128,
+}
+
+impl Direction {
+    pub const HORIZONTAL: Direction = Direction { dx: 1, dy: 0 };
+    pub const VERTICAL: Direction = Direction { dx: 0, dy: 1 };
+    pub const DIAGONAL: Direction = Direction { dx: 1, dy: 1 };
+    pub const ANTI_DIAGONAL: Direction = Direction { dx: 1, dy: -1 };
+
+    pub fn is_slanted(&self) -> bool {
+        ![
+            Direction::HORIZONTAL,
+            Direction::VERTICAL,
+            Direction::DIAGONAL,
+            Direction::ANTI_DIAGONAL,
+        ]
+        .contains(self)
+    }
+
+    /// Identifies the line through `(x, y)` among the parallel ones.
+    pub fn key(&self, (x, y): (i128, i128)) -> i128 {
+        self.dy * x - self.dx * y
+    }
+
+    /// Orders the points along a line, growing by `spacing` per step.
+    fn position(&self, (x, y): (i128, i128)) -> i128 {
+        self.dx * x + self.dy * y
+    }
+
+    fn spacing(&self) -> i128 {
+        self.dx * self.dx + self.dy * self.dy
+    }
+
+    /// The point at `position` on the line with `key`.
+    fn point(&self, key: i128, position: i128) -> (i128, i128) {
+        let spacing = self.spacing();
+        (
+            (self.dy * key + self.dx * position) / spacing,
+            (self.dy * position - self.dx * key) / spacing,
+        )
+    }
+}
+
+/// Where two segments meet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Intersection {
+    /// A single point, which need not be a lattice point.
+    Point(Ratio<i128>, Ratio<i128>),
+    /// Collinear segments sharing the part between two lattice points.
+    Overlap(Point, Point),
+}
+
+impl Line {
+    pub fn is_axis_aligned(&self) -> bool {
+        self.start.x == self.end.x || self.start.y == self.end.y
+    }
+
+    fn delta(&self) -> (i128, i128) {
+        (
+            self.end.x as i128 - self.start.x as i128,
+            self.end.y as i128 - self.start.y as i128,
+        )
+    }
+
+    /// The number of lattice points on the line, minus one.
+    pub fn steps(&self) -> u128 {
+        let (dx, dy) = self.delta();
+        dx.unsigned_abs().gcd(&dy.unsigned_abs())
+    }
+
+    /// A single point counts as horizontal.
+    pub fn direction(&self) -> Direction {
+        let (dx, dy) = self.delta();
+        let steps = dx.gcd(&dy).max(1);
+        let (dx, dy) = (dx / steps, dy / steps);
+        match (dx, dy) {
+            (0, 0) => Direction::HORIZONTAL,
+            _ if dx < 0 || (dx == 0 && dy < 0) => Direction { dx: -dx, dy: -dy },
+            _ => Direction { dx, dy },
+        }
+    }
+
+    fn start(&self) -> (i128, i128) {
+        (self.start.x as i128, self.start.y as i128)
+    }
+
+    fn end(&self) -> (i128, i128) {
+        (self.end.x as i128, self.end.y as i128)
+    }
+
+    /// Whether the line is in the range where its intersections can be
+    /// computed exactly.
+    pub fn is_valid(&self) -> bool {
+        !self.direction().is_slanted()
+            || [self.start.x, self.start.y, self.end.x, self.end.y]
+                .iter()
+                .all(|c| (-MAX_SLANTED..=MAX_SLANTED).contains(c))
+    }
+
+    pub fn get_points(&self) -> Vec<Point> {
+        let (dx, dy) = self.delta();
+        let steps = self.steps().max(1) as i128;
+        let (step_x, step_y) = ((dx / steps) as i64, (dy / steps) as i64);
+        let mut points = vec![self.start.clone()];
+        let mut point = self.start.clone();
+        while point != self.end {
+            point = point
+                + Point {
+                    x: step_x,
+                    y: step_y,
+                };
+            points.push(point.clone());
+        }
+        points
+    }
+
+    /// Where this segment meets `other`, computed exactly.
+    pub fn intersection(&self, other: &Line) -> Option<Intersection> {
+        let (d1, d2) = (self.direction(), other.direction());
+        let (k1, k2) = (d1.key(self.start()), d2.key(other.start()));
+        let det = d1.dx * d2.dy - d2.dx * d1.dy;
+        if det == 0 {
+            if k1 != k2 {
+                return None;
+            }
+            let range = |line: &Line| {
+                let (a, b) = (d1.position(line.start()), d1.position(line.end()));
+                (a.min(b), a.max(b))
+            };
+            let ((a1, b1), (a2, b2)) = (range(self), range(other));
+            let (start, end) = (a1.max(a2), b1.min(b2));
+            if start > end {
+                return None;
+            }
+            let point = |position| {
+                let (x, y) = d1.point(k1, position);
+                Point {
+                    x: x as i64,
+                    y: y as i64,
+                }
+            };
+            return Some(Intersection::Overlap(point(start), point(end)));
+        }
+        let x = Ratio::new(d1.dx * k2 - d2.dx * k1, det);
+        let y = Ratio::new(d1.dy * k2 - d2.dy * k1, det);
+        let within = |line: &Line| {
+            let ((x1, y1), (x2, y2)) = (line.start(), line.end());
+            let between = |v: &Ratio<i128>, a: i128, b: i128| {
+                (Ratio::from(a.min(b))..=Ratio::from(a.max(b))).contains(v)
+            };
+            between(&x, x1, x2) && between(&y, y1, y2)
+        };
+        (within(self) && within(other)).then_some(Intersection::Point(x, y))
+    }
+}
+
+pub struct Map {
+    pub counts: HashMap<Point, u32>,
+}
+
+impl Map {
+    pub fn new() -> Map {
+        Map {
+            counts: HashMap::new(),
+        }
+    }
+
+    pub fn add_point(&mut self, point: Point) {
+        *self.counts.entry(point).or_insert(0) += 1;
+    }
+
+    pub fn add_line(&mut self, line: &Line) {
+        for point in line.get_points() {
+            self.add_point(point);
+        }
+    }
+
+    pub fn coverage(&self) -> Coverage {
+        let mut coverage = Coverage::default();
+        for &count in self.counts.values() {
+            *coverage.points.entry(count as u64).or_insert(0) += 1;
+        }
+        coverage
+    }
+}
+
+/// Characters for increasing densities, from no line to the most lines.
+const SHADES: &[u8] = b" .:-=+*#%@";
+
+/// The grey of the least covered points in a PGM image, bright enough to
+/// tell apart from black.
+const DIMMEST_GREY: usize = 64;
+
+/// The heat of the least covered points in a PPM image, out of 765.
+const DIMMEST_HEAT: usize = 128;
+
+/// A rectangle of the map around all covered points, shrunk to fit a size
+/// limit. When shrunk, each cell shows the highest count among the points
+/// it covers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Heatmap {
+    /// The map point at the top left cell.
+    pub origin: Point,
+    /// How many map points each cell covers in each direction.
+    pub scale: u128,
+    pub width: usize,
+    pub height: usize,
+    pub cells: Vec<u32>,
+}
+
+impl Heatmap {
+    /// A heatmap of at most `max_size` cells in each direction. The lines
+    /// are drawn from their pieces of constant coverage and the crossings
+    /// between them, so the work depends on the number of lines and cells
+    /// rather than on the number of points.
+    pub fn new<'a>(lines: impl IntoIterator<Item = &'a Line>, max_size: usize) -> Heatmap {
+        let lines: Vec<&Line> = lines.into_iter().collect();
+        let xs = lines.iter().flat_map(|line| [line.start.x, line.end.x]);
+        let ys = lines.iter().flat_map(|line| [line.start.y, line.end.y]);
+        let (min_x, max_x) = (xs.clone().min().unwrap_or(0), xs.max().unwrap_or(-1));
+        let (min_y, max_y) = (ys.clone().min().unwrap_or(0), ys.max().unwrap_or(-1));
+        let extent = |min: i64, max: i64| (max as i128 - min as i128 + 1).max(0) as u128;
+        let (extent_x, extent_y) = (extent(min_x, max_x), extent(min_y, max_y));
+        let scale = extent_x
+            .max(extent_y)
+            .div_ceil(max_size.max(1) as u128)
+            .max(1);
+        let width = extent_x.div_ceil(scale) as usize;
+        let height = extent_y.div_ceil(scale) as usize;
+        let mut heatmap = Heatmap {
+            origin: Point { x: min_x, y: min_y },
+            scale,
+            width,
+            height,
+            cells: vec![0; width * height],
+        };
+
+        let mut pieces = pieces(lines);
+        for piece in pieces.iter() {
+            heatmap.draw(piece);
+        }
+        for ((x, y), counts) in crossings(&mut pieces) {
+            let count = counts.values().sum::<u64>();
+            heatmap.mark(heatmap.cell(x, min_x), heatmap.cell(y, min_y), count);
+        }
+        heatmap
+    }
+
+    fn cell(&self, value: i128, min: i64) -> usize {
+        ((value - min as i128) as u128 / self.scale) as usize
+    }
+
+    fn mark(&mut self, col: usize, row: usize, count: u64) {
+        let cell = &mut self.cells[row * self.width + col];
+        *cell = (*cell).max(count.min(u32::MAX as u64) as u32);
+    }
+
+    /// Marks the cells that `piece` passes through with its count, one band
+    /// of cells across its steeper axis at a time. Within a band the points
+    /// move less along the other axis than the band is wide, so they cover
+    /// every cell between the first and the last.
+    fn draw(&mut self, piece: &Piece) {
+        let direction = piece.direction;
+        let start = direction.point(piece.key, piece.start);
+        let steps = (piece.end - piece.start) / direction.spacing();
+        let (min_x, min_y) = (self.origin.x, self.origin.y);
+        let steep = direction.dy.abs() > direction.dx;
+        // Along the steeper axis, going from lower to higher values.
+        let (mut major, mut minor, mut step) = if steep {
+            (start.1, start.0, (direction.dy, direction.dx))
+        } else {
+            (start.0, start.1, (direction.dx, direction.dy))
+        };
+        if step.0 < 0 {
+            major += step.0 * steps;
+            minor += step.1 * steps;
+            step = (-step.0, -step.1);
+        }
+        let (major_min, minor_min) = if steep {
+            (min_y, min_x)
+        } else {
+            (min_x, min_y)
+        };
+        let first_band = self.cell(major, major_min);
+        let last_band = self.cell(major + step.0 * steps, major_min);
+        for band in first_band..=last_band {
+            let band_start = major_min as i128 + (band as u128 * self.scale) as i128;
+            let band_end = band_start + (self.scale - 1) as i128;
+            let first = (band_start.max(major) - major + step.0 - 1) / step.0;
+            let last = ((band_end - major) / step.0).min(steps);
+            if first > last {
+                continue;
+            }
+            let (a, b) = (
+                self.cell(minor + step.1 * first, minor_min),
+                self.cell(minor + step.1 * last, minor_min),
+            );
+            for other in a.min(b)..=a.max(b) {
+                let (col, row) = if steep { (other, band) } else { (band, other) };
+                self.mark(col, row, piece.count);
+            }
+        }
+    }
+
+    pub fn max(&self) -> u32 {
+        self.cells.iter().copied().max().unwrap_or(0)
+    }
+
+    /// The shade of `count` on a map whose highest count is `max`, 0 for no
+    /// lines and from `dimmest` up to `brightest` for the covered points.
+    fn level(count: u32, max: u32, dimmest: usize, brightest: usize) -> usize {
+        match count {
+            0 => 0,
+            _ => dimmest + (count as usize - 1) * (brightest - dimmest) / (max.max(2) as usize - 1),
+        }
+    }
+
+    /// The heatmap as text, followed by a legend of the counts each
+    /// character stands for.
+    pub fn to_ascii(&self) -> String {
+        let max = self.max();
+        let end = |min: i64, cells: usize| min as i128 + (cells as u128 * self.scale) as i128 - 1;
+        let mut out = format!(
+            "x {}..{}, y {}..{}, {} point(s) per cell\n",
+            self.origin.x,
+            end(self.origin.x, self.width),
+            self.origin.y,
+            end(self.origin.y, self.height),
+            self.scale
+        );
+        for row in self.cells.chunks(self.width.max(1)) {
+            out.extend(
+                row.iter()
+                    .map(|&count| SHADES[Self::level(count, max, 1, SHADES.len() - 1)] as char),
+            );
+            out += "\n";
+        }
+        let mut legend = vec![(SHADES[0], 0, 0)];
+        for count in 1..=max {
+            let shade = SHADES[Self::level(count, max, 1, SHADES.len() - 1)];
+            match legend.last_mut() {
+                Some((last, _, high)) if *last == shade => *high = count,
+                _ => legend.push((shade, count, count)),
+            }
+        }
+        let legend: Vec<String> = legend
+            .iter()
+            .map(|&(shade, low, high)| {
+                if low == high {
+                    format!("'{}' {}", shade as char, low)
+                } else {
+                    format!("'{}' {}-{}", shade as char, low, high)
+                }
+            })
+            .collect();
+        out + &format!("Legend: {}\n", legend.join(", "))
+    }
+
+    /// The rows of the image: the heatmap, a blank row and then a legend
+    /// strip running from no lines on the left to the maximum on the right.
+    fn image_rows(&self, max: u32) -> impl Iterator<Item = Vec<u32>> + '_ {
+        let legend: Vec<u32> = (0..self.width)
+            .map(|col| (col as u64 * max as u64 / (self.width.max(2) as u64 - 1)) as u32)
+            .collect();
+        let strip = (self.height / 20).max(1);
+        self.cells
+            .chunks(self.width.max(1))
+            .map(|row| row.to_vec())
+            .chain(std::iter::once(vec![0; self.width]))
+            .chain(std::iter::repeat_n(legend, strip))
+    }
+
+    /// A binary greyscale PGM image, white where the most lines overlap.
+    pub fn to_pgm(&self) -> Vec<u8> {
+        let max = self.max();
+        let rows: Vec<Vec<u32>> = self.image_rows(max).collect();
+        let mut out = format!("P5\n{} {}\n255\n", self.width, rows.len()).into_bytes();
+        for row in rows.iter() {
+            out.extend(
+                row.iter()
+                    .map(|&count| Self::level(count, max, DIMMEST_GREY, 255) as u8),
+            );
+        }
+        out
+    }
+
+    /// A binary colour PPM image, shading from black through red and
+    /// yellow to white where the most lines overlap.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let max = self.max();
+        let rows: Vec<Vec<u32>> = self.image_rows(max).collect();
+        let mut out = format!("P6\n{} {}\n255\n", self.width, rows.len()).into_bytes();
+        for row in rows.iter() {
+            for &count in row.iter() {
+                let heat = Self::level(count, max, DIMMEST_HEAT, 765) as i32;
+                out.extend([0, 255, 510].map(|offset| (heat - offset).clamp(0, 255) as u8));
+            }
+        }
+        out
+    }
+}
+
+impl Default for Map {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Line>, ParseError> {
+    parse::lines(input)
+        .map(|(i, text)| {
+            let line: Line = text
+                .parse()
+                .map_err(|e: ParseError| e.within(i, text, text))?;
+            if !line.is_valid() {
+                let expected = format!(
+                    "coordinates within {} of the origin on a slanted line",
+                    MAX_SLANTED
+                );
+                return Err(ParseError::at(i, text, text, &expected));
+            }
+            Ok(line)
+        })
+        .collect()
+}
+
+/// How many points are covered by exactly how many lines.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Coverage {
+    pub points: BTreeMap<u64, u128>,
+}
+
+impl Coverage {
+    /// The number of points covered by at least `lines` lines.
+    pub fn at_least(&self, lines: u64) -> u128 {
+        self.points.range(lines..).map(|(_, &points)| points).sum()
+    }
+
+    pub fn overlaps(&self) -> u128 {
+        self.at_least(2)
+    }
+}
+
+/// A run of lattice points on one line, covered by the same number of
+/// input lines in that direction. `start` and `end` are the positions of
+/// the first and last point along the line.
+#[derive(Debug, Clone, Copy)]
+struct Piece {
+    direction: Direction,
+    key: i128,
+    start: i128,
+    end: i128,
+    count: u64,
+}
+
+impl Piece {
+    fn points(&self) -> u128 {
+        ((self.end - self.start) / self.direction.spacing() + 1) as u128
+    }
+
+    fn x_range(&self) -> (i128, i128) {
+        let (start, _) = self.direction.point(self.key, self.start);
+        let (end, _) = self.direction.point(self.key, self.end);
+        (start, end)
+    }
+
+    fn contains(&self, point: (i128, i128)) -> bool {
+        self.direction.key(point) == self.key
+            && (self.start..=self.end).contains(&self.direction.position(point))
+    }
+
+    /// The lattice point where pieces of different directions cross.
+    fn crossing(&self, other: &Piece) -> Option<(i128, i128)> {
+        let (d1, d2) = (self.direction, other.direction);
+        let det = d1.dx * d2.dy - d2.dx * d1.dy;
+        let x = Ratio::new(d1.dx * other.key - d2.dx * self.key, det);
+        let y = Ratio::new(d1.dy * other.key - d2.dy * self.key, det);
+        if !x.is_integer() || !y.is_integer() {
+            return None;
+        }
+        let point = (x.to_integer(), y.to_integer());
+        (self.contains(point) && other.contains(point)).then_some(point)
+    }
+}
+
+/// Splits the lines of each family of collinear lines into pieces of
+/// constant coverage by sweeping over the start and end of every line.
+fn pieces<'a>(lines: impl IntoIterator<Item = &'a Line>) -> Vec<Piece> {
+    let mut events: BTreeMap<(Direction, i128), Vec<(i128, i64)>> = BTreeMap::new();
+    for line in lines {
+        assert!(line.is_valid(), "slanted line too far from the origin");
+        let direction = line.direction();
+        let (start, end) = (
+            direction.position(line.start()),
+            direction.position(line.end()),
+        );
+        let family = events
+            .entry((direction, direction.key(line.start())))
+            .or_default();
+        family.push((start.min(end), 1));
+        family.push((start.max(end) + direction.spacing(), -1));
+    }
+
+    let mut pieces = Vec::new();
+    for ((direction, key), mut events) in events {
+        events.sort_unstable();
+        let mut count = 0;
+        for (i, &(pos, change)) in events.iter().enumerate() {
+            count += change;
+            match events.get(i + 1) {
+                Some(&(next, _)) if count > 0 && next > pos => pieces.push(Piece {
+                    direction,
+                    key,
+                    start: pos,
+                    end: next - direction.spacing(),
+                    count: count as u64,
+                }),
+                _ => {}
+            }
+        }
+    }
+    pieces
+}
+
+/// The pieces whose x range is still open during a sweep over x, by
+/// direction and then by position across the sweep line.
+#[derive(Default)]
+struct Active<'a> {
+    pieces: BTreeMap<Direction, BTreeMap<(i128, i128), &'a Piece>>,
+    ends: BinaryHeap<Reverse<(i128, Direction, i128, i128)>>,
+}
+
+impl<'a> Active<'a> {
+    fn insert(&mut self, piece: &'a Piece) {
+        let (_, end) = piece.x_range();
+        self.ends
+            .push(Reverse((end, piece.direction, piece.key, piece.start)));
+        self.pieces
+            .entry(piece.direction)
+            .or_default()
+            .insert((piece.key, piece.start), piece);
+    }
+
+    /// Removes the pieces that end before `x`.
+    fn advance(&mut self, x: i128) {
+        while let Some(&Reverse((end, direction, key, start))) = self.ends.peek() {
+            if end >= x {
+                break;
+            }
+            self.ends.pop();
+            self.pieces
+                .get_mut(&direction)
+                .unwrap()
+                .remove(&(key, start));
+        }
+    }
+
+    /// The lattice points where `piece` crosses an active piece of another
+    /// direction. Only the active lines that `piece` spans are looked at,
+    /// and on each of them only the piece around the crossing.
+    fn crossings(&self, piece: &Piece) -> Vec<((i128, i128), &'a Piece)> {
+        let mut found = Vec::new();
+        for (&direction, pieces) in self.pieces.iter() {
+            if direction == piece.direction {
+                continue;
+            }
+            let keys = [piece.start, piece.end]
+                .map(|position| direction.key(piece.direction.point(piece.key, position)));
+            let (low, high) = (keys[0].min(keys[1]), keys[0].max(keys[1]));
+            let mut next = pieces.range((low, i128::MIN)..).next();
+            while let Some((&(key, _), _)) = next {
+                if key > high {
+                    break;
+                }
+                let line = Piece {
+                    direction,
+                    key,
+                    start: i128::MIN,
+                    end: i128::MAX,
+                    count: 0,
+                };
+                if let Some(point) = piece.crossing(&line) {
+                    let position = direction.position(point);
+                    let other = pieces.range((key, i128::MIN)..=(key, position)).next_back();
+                    if let Some((_, &other)) = other.filter(|(_, other)| other.end >= position) {
+                        found.push((point, other));
+                    }
+                }
+                next = pieces.range((key + 1, i128::MIN)..).next();
+            }
+        }
+        found
+    }
+}
+
+/// Counts the lattice points covered by the lines without visiting them.
+/// Overlapping collinear lines are merged into pieces by a sweep along each
+/// line, and crossings between directions are found by sweeping over x with
+/// the pieces whose x range is still open. Panics on lines that are not
+/// valid.
+pub fn coverage<'a>(lines: impl IntoIterator<Item = &'a Line>) -> Coverage {
+    let mut pieces = pieces(lines);
+    let mut coverage = Coverage::default();
+    for piece in pieces.iter() {
+        *coverage.points.entry(piece.count).or_insert(0) += piece.points();
+    }
+
+    // Each crossing was counted once per direction, replace those counts
+    // with a single point covered by all of them.
+    for counts in crossings(&mut pieces).values() {
+        for count in counts.values() {
+            *coverage.points.get_mut(count).unwrap() -= 1;
+        }
+        *coverage.points.entry(counts.values().sum()).or_insert(0) += 1;
+    }
+    coverage.points.retain(|_, &mut points| points > 0);
+    coverage
+}
+
+/// The lattice points where pieces of different directions cross, with the
+/// count of the piece in each direction there. Sorts `pieces` by x range.
+fn crossings(pieces: &mut [Piece]) -> HashMap<(i128, i128), BTreeMap<Direction, u64>> {
+    pieces.sort_unstable_by_key(|piece| piece.x_range());
+    let mut crossings: HashMap<(i128, i128), BTreeMap<Direction, u64>> = HashMap::new();
+    let mut active = Active::default();
+    for piece in pieces.iter() {
+        let (x, _) = piece.x_range();
+        active.advance(x);
+        for (point, other) in active.crossings(piece) {
+            let counts = crossings.entry(point).or_default();
+            counts.insert(piece.direction, piece.count);
+            counts.insert(other.direction, other.count);
+        }
+        active.insert(piece);
+    }
+    crossings
+}
+
+/// Counts overlaps by visiting every point, for checking `coverage`.
+pub fn coverage_naive<'a>(lines: impl IntoIterator<Item = &'a Line>) -> Coverage {
+    let mut map = Map::new();
+    for line in lines {
+        map.add_line(line);
+    }
+    map.coverage()
+}
+
+pub fn part1(input: &[Line]) -> u128 {
+    coverage(input.iter().filter(|line| line.is_axis_aligned())).overlaps()
+}
+
+pub fn part2(input: &[Line]) -> u128 {
+    coverage(input).overlaps()
+}
@@ -0,0 +1,156 @@
+use crate::parse::{self, ParseError};
+use crate::scan;
+use log::trace;
+use num::{BigUint, One, Zero};
+use std::ops::{Add, Mul};
+
+pub fn parse(input: &str) -> Result<Vec<u8>, ParseError> {
+    let line = parse::first_line(input, "comma-separated list")?;
+    scan::integers(line, 1, b",", "number from 0 to 255")
+}
+
+pub fn simulate_fish_naive(start_state: &[u8], num_days: u32) -> u64 {
+    let mut state = start_state.to_vec();
+    for _ in 0..num_days {
+        for i in 0..state.len() {
+            if state[i] == 0 {
+                state[i] = 6;
+                state.push(8);
+            } else {
+                state[i] -= 1;
+            }
+        }
+    }
+    state.len() as u64
+}
+
+pub fn simulate_fish(start_state: &[u8], num_days: u32) -> u64 {
+    // Room for the newborn timer of 8 and any higher starting timer.
+    let size = start_state
+        .iter()
+        .map(|&fish| fish as usize + 1)
+        .fold(9, usize::max);
+    let mut num_fish_with_days_left = vec![0u64; size];
+    let mut next_num_fish_with_days_left = vec![0u64; size];
+    for &fish in start_state.iter() {
+        num_fish_with_days_left[fish as usize] += 1;
+    }
+    for day in 0..num_days {
+        if day % 10 == 0 {
+            trace!(
+                "Day {}: {}",
+                day,
+                num_fish_with_days_left.iter().sum::<u64>()
+            );
+        }
+        next_num_fish_with_days_left[..size - 1].copy_from_slice(&num_fish_with_days_left[1..]);
+        next_num_fish_with_days_left[size - 1] = 0;
+        next_num_fish_with_days_left[8] += num_fish_with_days_left[0];
+        next_num_fish_with_days_left[6] += num_fish_with_days_left[0];
+        num_fish_with_days_left.clone_from(&next_num_fish_with_days_left);
+    }
+    num_fish_with_days_left.iter().sum()
+}
+
+/// The timers of the lanternfish cycle: a fish whose timer runs out
+/// restarts at `reset` and spawns a fish starting at `newborn`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timers {
+    pub reset: usize,
+    pub newborn: usize,
+}
+
+impl Default for Timers {
+    fn default() -> Timers {
+        Timers {
+            reset: 6,
+            newborn: 8,
+        }
+    }
+}
+
+type Matrix<T> = Vec<Vec<T>>;
+
+fn multiply<T>(a: &Matrix<T>, b: &Matrix<T>, reduce: &impl Fn(T) -> T) -> Matrix<T>
+where
+    T: Clone + Zero + Add<Output = T> + Mul<Output = T>,
+{
+    let n = a.len();
+    let mut product = vec![vec![T::zero(); n]; n];
+    for (i, row) in product.iter_mut().enumerate() {
+        for (j, cell) in row.iter_mut().enumerate() {
+            for k in 0..n {
+                let term = reduce(a[i][k].clone() * b[k][j].clone());
+                *cell = reduce(cell.clone() + term);
+            }
+        }
+    }
+    product
+}
+
+/// The number of fish after `num_days`, from the daily transition matrix
+/// raised to that power by repeated squaring. `reduce` is applied after
+/// every operation.
+fn population<T>(start_state: &[u8], num_days: u64, timers: Timers, reduce: impl Fn(T) -> T) -> T
+where
+    T: Clone + Zero + One + Add<Output = T> + Mul<Output = T>,
+{
+    let max_start = start_state.iter().copied().max().unwrap_or(0) as usize;
+    let size = timers.reset.max(timers.newborn).max(max_start) + 1;
+
+    // transition[i][j] is how many fish with timer i a fish with timer j
+    // turns into in one day.
+    let mut transition = vec![vec![T::zero(); size]; size];
+    for timer in 1..size {
+        transition[timer - 1][timer] = T::one();
+    }
+    transition[timers.reset][0] = transition[timers.reset][0].clone() + T::one();
+    transition[timers.newborn][0] = transition[timers.newborn][0].clone() + T::one();
+
+    let mut power: Matrix<T> = (0..size)
+        .map(|i| {
+            (0..size)
+                .map(|j| if i == j { T::one() } else { T::zero() })
+                .collect()
+        })
+        .collect();
+    let mut days = num_days;
+    while days > 0 {
+        if days % 2 == 1 {
+            power = multiply(&power, &transition, &reduce);
+        }
+        transition = multiply(&transition, &transition, &reduce);
+        days /= 2;
+    }
+
+    // Each starting fish turns into as many fish as its column sums to.
+    let mut total = T::zero();
+    for &fish in start_state.iter() {
+        for row in power.iter() {
+            total = reduce(total + row[fish as usize].clone());
+        }
+    }
+    total
+}
+
+/// The exact number of fish after `num_days`. The count grows by about 9%
+/// a day, so this is only practical up to a few hundred thousand days.
+pub fn population_exact(start_state: &[u8], num_days: u64, timers: Timers) -> BigUint {
+    population(start_state, num_days, timers, |n| n)
+}
+
+/// The number of fish after `num_days` modulo `modulus`, in O(log
+/// `num_days`) time.
+pub fn population_mod(start_state: &[u8], num_days: u64, timers: Timers, modulus: u64) -> u64 {
+    assert!(modulus > 0, "modulus must be positive");
+    let modulus = modulus as u128;
+    population(start_state, num_days, timers, |n: u128| n % modulus) as u64
+}
+
+pub fn part1(input: &[u8]) -> u64 {
+    simulate_fish_naive(input, 80)
+}
+
+pub fn part2(input: &[u8]) -> BigUint {
+    population_exact(input, 256, Timers::default())
+}
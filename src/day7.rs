@@ -0,0 +1,46 @@
+use crate::parse::{self, ParseError};
+use crate::scan;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoCrabs;
+
+impl fmt::Display for NoCrabs {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "No crabs to align")
+    }
+}
+
+impl std::error::Error for NoCrabs {}
+
+pub fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+    let line = parse::first_line(input, "comma-separated list")?;
+    scan::integers(line, 1, b",", "integer")
+}
+
+pub fn find_least_fuel_to_align(
+    start_positions: &[i32],
+    cost: fn(i32) -> i64,
+) -> Result<i64, NoCrabs> {
+    let &min = start_positions.iter().min().ok_or(NoCrabs)?;
+    let &max = start_positions.iter().max().ok_or(NoCrabs)?;
+    let mut best_fuel_used = i64::MAX;
+    for pos in min..=max {
+        let mut fuel_used = 0;
+        for &crab_pos in start_positions.iter() {
+            fuel_used += cost((crab_pos - pos).abs());
+        }
+        if fuel_used < best_fuel_used {
+            best_fuel_used = fuel_used;
+        }
+    }
+    Ok(best_fuel_used)
+}
+
+pub fn part1(input: &[i32]) -> Result<i64, NoCrabs> {
+    find_least_fuel_to_align(input, |dist| dist as i64)
+}
+
+pub fn part2(input: &[i32]) -> Result<i64, NoCrabs> {
+    find_least_fuel_to_align(input, |dist| dist as i64 * (dist as i64 + 1) / 2)
+}
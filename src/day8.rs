@@ -0,0 +1,193 @@
+use crate::parse::{self, ParseError};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::str::FromStr;
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+use strum_macros::EnumString;
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone, EnumString, EnumIter)]
+#[allow(non_camel_case_types)]
+pub enum Segment {
+    a,
+    b,
+    c,
+    d,
+    e,
+    f,
+    g,
+}
+
+pub type DisplayState = HashSet<Segment>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WiringError {
+    /// The patterns fit more than one wire for this segment.
+    Ambiguous(Segment),
+    /// The patterns fit no wire for this segment.
+    Unmatched(Segment),
+    /// An output lights up segments that form no digit.
+    NotADigit,
+}
+
+impl fmt::Display for WiringError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WiringError::Ambiguous(segment) => {
+                write!(f, "Segment {:?} could be wired to several others", segment)
+            }
+            WiringError::Unmatched(segment) => {
+                write!(f, "Segment {:?} fits none of the wires", segment)
+            }
+            WiringError::NotADigit => write!(f, "An output is not a digit"),
+        }
+    }
+}
+
+impl std::error::Error for WiringError {}
+
+pub type Entry = ([DisplayState; 10], [DisplayState; 4]);
+
+pub fn parse_set(s: &str) -> DisplayState {
+    let mut set = HashSet::new();
+    for char in s.chars() {
+        set.insert(Segment::from_str(&char.to_string()).unwrap());
+    }
+    set
+}
+
+pub fn true_digits() -> [DisplayState; 10] {
+    [
+        parse_set("abcefg"),  // 0
+        parse_set("cf"),      // 1
+        parse_set("acdeg"),   // 2
+        parse_set("acdfg"),   // 3
+        parse_set("bcdf"),    // 4
+        parse_set("abdfg"),   // 5
+        parse_set("abdefg"),  // 6
+        parse_set("acf"),     // 7
+        parse_set("abcdefg"), // 8
+        parse_set("abcdfg"),  // 9
+    ]
+}
+
+fn parse_digits<const N: usize>(
+    i: usize,
+    line: &str,
+    part: &str,
+) -> Result<[DisplayState; N], ParseError> {
+    let words = parse::fields::<N>(i, line, part, " ")?;
+    let mut digits: [DisplayState; N] = std::array::from_fn(|_| HashSet::new());
+    for (digit, word) in digits.iter_mut().zip(words) {
+        for k in 0..word.len() {
+            let segment = word.get(k..k + 1).unwrap_or(word);
+            digit.insert(parse::token(i, line, segment, "segment a-g")?);
+        }
+    }
+    Ok(digits)
+}
+
+pub fn parse(input: &str) -> Result<Vec<Entry>, ParseError> {
+    let mut inputs = Vec::new();
+    for (i, line) in parse::lines(input) {
+        let [first_part, second_part] = parse::fields(i, line, line, " | ")?;
+        let all_digits = parse_digits::<10>(i, line, first_part)?;
+        let target_digits = parse_digits::<4>(i, line, second_part)?;
+        inputs.push((all_digits, target_digits));
+    }
+    Ok(inputs)
+}
+
+pub fn count_unique(input: &[Entry]) -> u32 {
+    let mut count = 0;
+    for (_, target_digits) in input.iter() {
+        for digit in target_digits {
+            count += match digit.len() {
+                2 | 3 | 4 | 7 => 1,
+                _ => 0,
+            }
+        }
+    }
+    count
+}
+
+pub fn parse_segments(
+    input: &[Entry],
+    true_digits: &[DisplayState; 10],
+) -> Result<Vec<u64>, WiringError> {
+    let mut numbers = vec![];
+    for (input_digits, target_digits) in input.iter() {
+        let mapping = get_segment_mapping(input_digits, true_digits)?;
+        let mut number = 0;
+        for digit in target_digits {
+            let true_digit = map_digit(digit, &mapping);
+            let num = true_digits
+                .iter()
+                .position(|x| *x == true_digit)
+                .ok_or(WiringError::NotADigit)?;
+            number = number * 10 + num as u64;
+        }
+        numbers.push(number);
+    }
+    Ok(numbers)
+}
+
+pub fn get_segment_mapping(
+    input_digits: &[DisplayState; 10],
+    true_digits: &[DisplayState; 10],
+) -> Result<HashMap<Segment, Segment>, WiringError> {
+    let input_stats = get_segment_statistics(input_digits);
+    let true_stats = get_segment_statistics(true_digits);
+    let mut mapping = HashMap::new();
+    for seg1 in Segment::iter() {
+        for seg2 in Segment::iter() {
+            if input_stats[&seg1] == true_stats[&seg2] {
+                if mapping.contains_key(&seg1) {
+                    return Err(WiringError::Ambiguous(seg1));
+                }
+                mapping.insert(seg1, seg2);
+            }
+        }
+        if !mapping.contains_key(&seg1) {
+            return Err(WiringError::Unmatched(seg1));
+        }
+    }
+    Ok(mapping)
+}
+
+pub fn get_segment_statistics(
+    digits: &[DisplayState; 10],
+) -> HashMap<Segment, HashMap<usize, usize>> {
+    let mut stats = HashMap::new();
+    for seg in Segment::iter() {
+        let mut counts = HashMap::new();
+        for seg2 in Segment::iter() {
+            if seg2 == seg {
+                continue;
+            }
+            let count: usize = digits
+                .iter()
+                .filter(|&x| x.contains(&seg) && x.contains(&seg2))
+                .count();
+            *counts.entry(count).or_insert(0) += 1;
+        }
+        stats.insert(seg, counts);
+    }
+    stats
+}
+
+pub fn map_digit(digit: &DisplayState, mapping: &HashMap<Segment, Segment>) -> DisplayState {
+    let mut new = HashSet::new();
+    for segment in digit.iter() {
+        new.insert(*mapping.get(segment).unwrap());
+    }
+    new
+}
+
+pub fn part1(input: &[Entry]) -> u32 {
+    count_unique(input)
+}
+
+pub fn part2(input: &[Entry]) -> Result<u64, WiringError> {
+    Ok(parse_segments(input, &true_digits())?.iter().sum())
+}
@@ -0,0 +1,85 @@
+use crate::grid::{Grid, Pos};
+use crate::parse::ParseError;
+use colored::*;
+use std::collections::HashSet;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TooFewBasins(pub usize);
+
+impl fmt::Display for TooFewBasins {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Only {} basin(s), need 3", self.0)
+    }
+}
+
+impl std::error::Error for TooFewBasins {}
+
+pub fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse_digits(input)
+}
+
+pub fn get_lowest_points(map: &Grid<u8>) -> HashSet<Pos> {
+    map.indexed_iter()
+        .filter(|&(pos, &val)| map.neighbors4(pos).all(|n| map[n] > val))
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+pub fn get_basin(map: &Grid<u8>, point: &Pos) -> HashSet<Pos> {
+    let mut visited = HashSet::new();
+    let mut to_visit = vec![*point];
+    while let Some(pos) = to_visit.pop() {
+        visited.insert(pos);
+        for p in map.neighbors4(pos) {
+            if map[p] == 9 {
+                continue;
+            }
+            if !visited.contains(&p) {
+                to_visit.push(p);
+            }
+        }
+    }
+    visited
+}
+
+pub fn get_basins(map: &Grid<u8>) -> Vec<HashSet<Pos>> {
+    let points = get_lowest_points(map);
+    let mut basins = points.iter().map(|p| get_basin(map, p)).collect::<Vec<_>>();
+    basins.sort_by_key(|b| b.len());
+    basins.reverse();
+    basins
+}
+
+pub fn print_basins(map: &Grid<u8>) {
+    let points = get_lowest_points(map);
+    let basins = get_basins(map);
+    let biggest = basins.iter().take(3).flatten().collect::<HashSet<_>>();
+    for (i, row) in map.rows().enumerate() {
+        for (j, val) in row.iter().enumerate() {
+            if points.contains(&(i, j)) {
+                print!("{}", val.to_string().green());
+            } else if biggest.contains(&(i, j)) {
+                print!("{}", val.to_string().red());
+            } else {
+                print!("{}", val);
+            }
+        }
+        println!();
+    }
+}
+
+pub fn part1(input: &Grid<u8>) -> u32 {
+    get_lowest_points(input)
+        .iter()
+        .map(|&pos| input[pos] as u32 + 1)
+        .sum()
+}
+
+pub fn part2(input: &Grid<u8>) -> Result<usize, TooFewBasins> {
+    let basins = get_basins(input);
+    if basins.len() < 3 {
+        return Err(TooFewBasins(basins.len()));
+    }
+    Ok(basins[0..3].iter().map(|b| b.len()).product())
+}
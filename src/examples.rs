@@ -0,0 +1,229 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// The example blocks and emphasized answers found in a puzzle description.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Examples {
+    pub blocks: Vec<String>,
+    /// The last emphasized answer in each part's text.
+    pub answers: [Option<String>; 2],
+}
+
+/// Extracts examples from a saved puzzle page, either the HTML from the site
+/// or a Markdown conversion of it.
+pub fn extract(page: &str) -> Examples {
+    if page.contains("<pre>") || page.contains("<article") {
+        extract_html(page)
+    } else {
+        extract_markdown(page)
+    }
+}
+
+/// Returns the text between each `open` and the following `close`.
+fn between<'a>(text: &'a str, open: &str, close: &str) -> Vec<&'a str> {
+    let mut found = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find(open) {
+        rest = &rest[start + open.len()..];
+        match rest.find(close) {
+            Some(end) => {
+                found.push(&rest[..end]);
+                rest = &rest[end + close.len()..];
+            }
+            None => break,
+        }
+    }
+    found
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+fn with_newline(block: &str) -> String {
+    let mut block = block.trim_end_matches('\n').to_string();
+    block.push('\n');
+    block
+}
+
+fn extract_html(page: &str) -> Examples {
+    let mut sections: Vec<&str> = between(page, "<article", "</article>");
+    if sections.is_empty() {
+        sections.push(page);
+    }
+    let mut examples = Examples::default();
+    for (i, section) in sections.iter().enumerate() {
+        examples.blocks.extend(
+            between(section, "<pre><code>", "</code></pre>")
+                .into_iter()
+                .map(|block| with_newline(&strip_tags(block))),
+        );
+        // The answer is the last emphasized code in the section, whichever
+        // way round the tags are nested.
+        let answers = between(section, "<code><em>", "</em></code>")
+            .into_iter()
+            .chain(between(section, "<em><code>", "</code></em>"));
+        let offset = |answer: &&str| answer.as_ptr() as usize - section.as_ptr() as usize;
+        if let Some(answer) = answers.max_by_key(offset) {
+            examples.answers[i.min(1)] = Some(strip_tags(answer).trim().to_string());
+        }
+    }
+    examples
+}
+
+/// Splits Markdown into fenced code blocks and the remaining prose.
+fn split_fences(section: &str) -> (Vec<String>, String) {
+    let mut blocks = Vec::new();
+    let mut prose = String::new();
+    let mut block: Option<String> = None;
+    for line in section.lines() {
+        match (&mut block, line.trim_start().starts_with("```")) {
+            (None, true) => block = Some(String::new()),
+            (Some(_), true) => blocks.push(with_newline(&block.take().unwrap())),
+            (Some(block), false) => {
+                block.push_str(line);
+                block.push('\n');
+            }
+            (None, false) => {
+                prose.push_str(line);
+                prose.push('\n');
+            }
+        }
+    }
+    (blocks, prose)
+}
+
+/// Code spans that are emphasized, written as ``*`x`*``, ``**`x`**`` or
+/// ``` `*x*` ```.
+fn emphasized_code(prose: &str) -> Vec<String> {
+    let mut found = Vec::new();
+    let mut rest = prose;
+    while let Some(start) = rest.find('`') {
+        let before = rest[..start].ends_with('*');
+        let after_open = &rest[start + 1..];
+        let Some(end) = after_open.find('`') else {
+            break;
+        };
+        let code = &after_open[..end];
+        rest = &after_open[end + 1..];
+        if before && rest.starts_with('*') {
+            found.push(code.to_string());
+        } else if code.len() > 2 && code.starts_with('*') && code.ends_with('*') {
+            found.push(code.trim_matches('*').to_string());
+        }
+    }
+    found
+}
+
+fn extract_markdown(page: &str) -> Examples {
+    let (part1, part2) = match page.find("Part Two") {
+        Some(i) => (&page[..i], Some(&page[i..])),
+        None => (page, None),
+    };
+    let mut examples = Examples::default();
+    for (i, section) in [Some(part1), part2].into_iter().flatten().enumerate() {
+        let (blocks, prose) = split_fences(section);
+        examples.blocks.extend(blocks);
+        examples.answers[i] = emphasized_code(&prose).pop();
+    }
+    examples
+}
+
+/// Escapes backslashes and line breaks so `answer` fits on one line.
+fn escape(answer: &str) -> String {
+    let mut out = String::new();
+    for c in answer.chars() {
+        match c {
+            '\\' => out += "\\\\",
+            '\n' => out += "\\n",
+            '\r' => out += "\\r",
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+fn unescape(escaped: &str) -> Option<String> {
+    let mut out = String::new();
+    let mut chars = escaped.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        out.push(match chars.next()? {
+            '\\' => '\\',
+            'n' => '\n',
+            'r' => '\r',
+            _ => return None,
+        });
+    }
+    Some(out)
+}
+
+/// Expected example answers keyed by day and part, stored as one
+/// `day part answer` line per entry. The answer is the rest of the line,
+/// with backslashes and line breaks escaped as `\\`, `\n` and `\r`, so it
+/// may contain spaces and span several lines.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub answers: BTreeMap<(u32, usize), String>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> io::Result<Answers> {
+        let mut answers = Answers::default();
+        for (i, line) in fs::read_to_string(path)?.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let fields: Vec<&str> = line.splitn(3, ' ').collect();
+            let entry = match fields.as_slice() {
+                [day, part, answer] => match (day.parse(), part.parse(), unescape(answer)) {
+                    (Ok(day), Ok(part @ 1..=2), Some(answer)) => Some(((day, part), answer)),
+                    _ => None,
+                },
+                _ => None,
+            };
+            match entry {
+                Some((key, answer)) => {
+                    answers.answers.insert(key, answer);
+                }
+                None => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("{}:{}: malformed answer entry", path.display(), i + 1),
+                    ))
+                }
+            }
+        }
+        Ok(answers)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut out = String::new();
+        for ((day, part), answer) in self.answers.iter() {
+            out += &format!("{} {} {}\n", day, part, escape(answer));
+        }
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, out)
+    }
+}
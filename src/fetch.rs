@@ -0,0 +1,169 @@
+use crate::input::{self, Input};
+use log::warn;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u32 = 2021;
+const USER_AGENT: &str = concat!("advent_of_code_2021/", env!("CARGO_PKG_VERSION"));
+
+#[derive(Debug)]
+pub enum FetchError {
+    /// No cached input and no session token to download it with.
+    NoSession(u32),
+    /// The server answered with something other than 200.
+    Status(u32, u16),
+    /// The server could not be reached and there is no cached input.
+    Network(u32, String),
+    Io(PathBuf, io::Error),
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FetchError::NoSession(day) => write!(
+                f,
+                "No cached input for day {} and AOC_SESSION is not set",
+                day
+            ),
+            FetchError::Status(day, status) => {
+                write!(
+                    f,
+                    "Downloading input for day {} failed with status {}",
+                    day, status
+                )
+            }
+            FetchError::Network(day, error) => {
+                write!(f, "Could not download input for day {}: {}", day, error)
+            }
+            FetchError::Io(path, error) => write!(f, "{}: {}", path.display(), error),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+pub fn session_from_env() -> Option<String> {
+    env::var("AOC_SESSION")
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+pub(crate) fn agent() -> ureq::Agent {
+    ureq::AgentBuilder::new()
+        .timeout(Duration::from_secs(10))
+        .user_agent(USER_AGENT)
+        .build()
+}
+
+/// Downloads puzzle inputs and keeps them in a cache directory, using the
+/// same `N.txt` names as `read_input`.
+pub struct Fetcher {
+    base_url: String,
+    session: Option<String>,
+    cache_dir: PathBuf,
+    agent: ureq::Agent,
+}
+
+impl Fetcher {
+    pub fn new(session: Option<String>) -> Fetcher {
+        Fetcher {
+            base_url: BASE_URL.to_string(),
+            session,
+            cache_dir: input::inputs_dir(),
+            agent: agent(),
+        }
+    }
+
+    /// Takes the session token from the `AOC_SESSION` environment variable.
+    pub fn from_env() -> Fetcher {
+        Fetcher::new(session_from_env())
+    }
+
+    pub fn with_base_url(mut self, base_url: &str) -> Fetcher {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn with_cache_dir(mut self, cache_dir: impl Into<PathBuf>) -> Fetcher {
+        self.cache_dir = cache_dir.into();
+        self
+    }
+
+    pub fn cache_path(&self, day: u32) -> PathBuf {
+        self.cache_dir.join(format!("{}.txt", day))
+    }
+
+    pub fn url(&self, day: u32) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, YEAR, day)
+    }
+
+    /// Returns the cached input for `day`, downloading it first if it is not
+    /// cached yet or `refresh` is set. If the download fails because the
+    /// server cannot be reached, an existing cache entry is used instead.
+    pub fn fetch(&self, day: u32, refresh: bool) -> Result<Input, FetchError> {
+        let path = self.cache_path(day);
+        let cached = read_cache(&path)?;
+        if let (Some(input), false) = (&cached, refresh) {
+            return Ok(input.clone());
+        }
+        match self.download(day) {
+            Ok(text) => {
+                write_cache(&path, &text)?;
+                Ok(Input {
+                    name: path.display().to_string(),
+                    text,
+                })
+            }
+            Err(FetchError::Network(_, error)) if cached.is_some() => {
+                warn!("offline ({}), using cached {}", error, path.display());
+                Ok(cached.unwrap())
+            }
+            Err(FetchError::NoSession(_)) if cached.is_some() => {
+                warn!("AOC_SESSION is not set, using cached {}", path.display());
+                Ok(cached.unwrap())
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    fn download(&self, day: u32) -> Result<String, FetchError> {
+        let session = self.session.as_ref().ok_or(FetchError::NoSession(day))?;
+        let response = self
+            .agent
+            .get(&self.url(day))
+            .set("Cookie", &format!("session={}", session))
+            .call();
+        match response {
+            Ok(response) if response.status() == 200 => response
+                .into_string()
+                .map_err(|error| FetchError::Network(day, error.to_string())),
+            Ok(response) => Err(FetchError::Status(day, response.status())),
+            Err(ureq::Error::Status(status, _)) => Err(FetchError::Status(day, status)),
+            Err(ureq::Error::Transport(error)) => Err(FetchError::Network(day, error.to_string())),
+        }
+    }
+}
+
+fn read_cache(path: &Path) -> Result<Option<Input>, FetchError> {
+    match fs::read_to_string(path) {
+        Ok(text) => Ok(Some(Input {
+            name: path.display().to_string(),
+            text,
+        })),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(FetchError::Io(path.to_path_buf(), error)),
+    }
+}
+
+fn write_cache(path: &Path, text: &str) -> Result<(), FetchError> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|error| FetchError::Io(dir.to_path_buf(), error))?;
+    }
+    fs::write(path, text).map_err(|error| FetchError::Io(path.to_path_buf(), error))
+}
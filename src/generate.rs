@@ -0,0 +1,607 @@
+use crate::day3::{self, Report};
+use crate::grid::Grid;
+use crate::vec3::{Rotation, Vec3};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use std::collections::{BTreeSet, VecDeque};
+
+/// Generates a random, valid input for `day`, or `None` for unknown days.
+/// The same `size` and `seed` always give the same input. What `size`
+/// counts depends on the day, e.g. depths for day 1, boards for day 4 and
+/// the side length of the grid for day 9.
+pub fn generate(day: u32, size: usize, seed: u64) -> Option<String> {
+    let rng = &mut StdRng::seed_from_u64(seed);
+    let size = size.max(1);
+    let text = match day {
+        1 => depths(rng, size),
+        2 => commands(rng, size),
+        3 => diagnostics(rng, size),
+        4 => bingo(rng, size),
+        5 => vents(rng, size),
+        6 => timers(rng, size),
+        7 => crabs(rng, size),
+        8 => displays(rng, size),
+        9 => basins(rng, size),
+        10 => brackets(rng, size),
+        11 => octopuses(rng, size),
+        12 => caves(rng, size),
+        13 => transparent_paper(rng, size),
+        14 => polymer(rng, size),
+        15 => digit_grid(rng, size, 1..=9),
+        16 => transmission(rng, size),
+        17 => target_area(rng, size),
+        18 => snailfish(rng, size),
+        19 => scanners(rng, size).0,
+        20 => trench_map(rng, size),
+        21 => starting_positions(rng),
+        22 => reboot_steps(rng, size),
+        23 => amphipods(rng),
+        _ => return None,
+    };
+    Some(text)
+}
+
+/// The `size` `aoc-gen` uses for `day` when none is given, about that of
+/// a real puzzle input. Much larger caves or reboot steps take too long to
+/// solve.
+pub fn default_size(day: u32) -> usize {
+    match day {
+        1 => 2000,
+        4 | 9 | 10 | 15 | 17 | 18 | 20 => 100,
+        5 => 500,
+        6 => 300,
+        8 => 200,
+        11 | 12 => 10,
+        13 => 800,
+        14 => 20,
+        16 => 50,
+        19 => 30,
+        21 | 23 => 1,
+        22 => 420,
+        _ => 1000,
+    }
+}
+
+fn lines(lines: impl IntoIterator<Item = String>) -> String {
+    lines.into_iter().map(|line| line + "\n").collect()
+}
+
+fn join<T: ToString>(values: impl IntoIterator<Item = T>, separator: &str) -> String {
+    values
+        .into_iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+fn depths(rng: &mut StdRng, size: usize) -> String {
+    let mut depth: i64 = rng.gen_range(100..200);
+    lines((0..size).map(|_| {
+        depth = (depth + rng.gen_range(-20..=30)).max(0);
+        depth.to_string()
+    }))
+}
+
+fn commands(rng: &mut StdRng, size: usize) -> String {
+    lines((0..size).map(|_| {
+        let direction = ["forward", "down", "up"].choose(rng).unwrap();
+        format!("{} {}", direction, rng.gen_range(1..10))
+    }))
+}
+
+/// Between 2 and 4096 distinct 12 bit numbers for which both ratings are
+/// well defined.
+fn diagnostics(rng: &mut StdRng, size: usize) -> String {
+    let mut numbers: Vec<u64> = (0..1 << 12).collect();
+    let size = size.clamp(2, numbers.len());
+    loop {
+        numbers.shuffle(rng);
+        let report = Report {
+            width: 12,
+            numbers: numbers[..size].to_vec(),
+        };
+        let diagnostics = day3::analyze(&report);
+        if diagnostics.oxygen_generator.is_ok() && diagnostics.co2_scrubber.is_ok() {
+            return lines(report.numbers.iter().map(|n| format!("{:012b}", n)));
+        }
+    }
+}
+
+fn bingo(rng: &mut StdRng, size: usize) -> String {
+    let mut numbers: Vec<u8> = (0..100).collect();
+    numbers.shuffle(rng);
+    let mut out = join(numbers.iter(), ",") + "\n";
+    for _ in 0..size {
+        out += "\n";
+        let board: Vec<u8> = numbers.choose_multiple(rng, 25).copied().collect();
+        for row in board.chunks(5) {
+            out += &join(row.iter().map(|n| format!("{:2}", n)), " ");
+            out += "\n";
+        }
+    }
+    out
+}
+
+fn vents(rng: &mut StdRng, size: usize) -> String {
+    lines((0..size).map(|_| {
+        let (x1, y1) = (rng.gen_range(0..1000), rng.gen_range(0..1000));
+        let (x2, y2) = match rng.gen_range(0..3) {
+            0 => (x1, rng.gen_range(0..1000)),
+            1 => (rng.gen_range(0..1000), y1),
+            _ => {
+                let d: i32 = rng.gen_range(0..1000);
+                let (dx, dy) = (*[-1, 1].choose(rng).unwrap(), *[-1, 1].choose(rng).unwrap());
+                let d = (0..=d)
+                    .rev()
+                    .find(|&d| {
+                        (0..1000).contains(&(x1 + dx * d)) && (0..1000).contains(&(y1 + dy * d))
+                    })
+                    .unwrap();
+                (x1 + dx * d, y1 + dy * d)
+            }
+        };
+        format!("{},{} -> {},{}", x1, y1, x2, y2)
+    }))
+}
+
+fn timers(rng: &mut StdRng, size: usize) -> String {
+    join((0..size).map(|_| rng.gen_range(1..=5)), ",") + "\n"
+}
+
+fn crabs(rng: &mut StdRng, size: usize) -> String {
+    join((0..size).map(|_| rng.gen_range(0..size.max(2))), ",") + "\n"
+}
+
+fn displays(rng: &mut StdRng, size: usize) -> String {
+    const DIGITS: [&str; 10] = [
+        "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+    ];
+    lines((0..size).map(|_| {
+        let mut wiring: Vec<char> = "abcdefg".chars().collect();
+        wiring.shuffle(rng);
+        let scramble = |rng: &mut StdRng, digit: &str| {
+            let mut wires: Vec<char> = digit.bytes().map(|b| wiring[(b - b'a') as usize]).collect();
+            wires.shuffle(rng);
+            wires.into_iter().collect::<String>()
+        };
+        let mut patterns: Vec<String> = DIGITS.iter().map(|d| scramble(rng, d)).collect();
+        patterns.shuffle(rng);
+        let output: Vec<String> = (0..4)
+            .map(|_| {
+                let digit = DIGITS[rng.gen_range(0..10)];
+                scramble(rng, digit)
+            })
+            .collect();
+        format!("{} | {}", patterns.join(" "), output.join(" "))
+    }))
+}
+
+/// A `size` by `size` grid of digits from `digits`.
+fn digit_grid(rng: &mut StdRng, size: usize, digits: std::ops::RangeInclusive<u8>) -> String {
+    lines((0..size).map(|_| {
+        (0..size)
+            .map(|_| char::from(b'0' + rng.gen_range(digits.clone())))
+            .collect()
+    }))
+}
+
+/// A `size` by `size` height map of basins walled off from each other by
+/// 9s. Each basin slopes up from a single low point, so flooding from the
+/// low points finds every basin exactly once. Maps much smaller than 10 by
+/// 10 can have fewer than the three basins part 2 needs.
+fn basins(rng: &mut StdRng, size: usize) -> String {
+    // Grow regions from random seeds and put walls where they meet.
+    let mut region = Grid::new(size, size, usize::MAX);
+    let positions: Vec<_> = region.positions().collect();
+    let seeds = positions.choose_multiple(rng, (size * size / 25).max(6));
+    let mut queue = VecDeque::new();
+    for (i, &pos) in seeds.enumerate() {
+        region[pos] = i;
+        queue.push_back(pos);
+    }
+    while let Some(pos) = queue.pop_front() {
+        for n in region.neighbors4(pos).collect::<Vec<_>>() {
+            if region[n] == usize::MAX {
+                region[n] = region[pos];
+                queue.push_back(n);
+            }
+        }
+    }
+    let wall = Grid::from_fn(size, size, |(row, col)| {
+        [(row + 1, col), (row, col + 1)]
+            .into_iter()
+            .any(|n| region.get(n).is_some_and(|&r| r != region[(row, col)]))
+    });
+
+    // Every connected stretch of floor is a basin, rising from a random low
+    // point by one per step up to 8.
+    let mut heights: Grid<Option<u8>> = Grid::new(size, size, None);
+    let mut seen = Grid::new(size, size, false);
+    for start in wall.positions() {
+        if wall[start] || seen[start] {
+            continue;
+        }
+        seen[start] = true;
+        let mut basin = vec![start];
+        let mut i = 0;
+        while i < basin.len() {
+            for n in wall.neighbors4(basin[i]) {
+                if !wall[n] && !seen[n] {
+                    seen[n] = true;
+                    basin.push(n);
+                }
+            }
+            i += 1;
+        }
+        let low = *basin.choose(rng).unwrap();
+        heights[low] = Some(0);
+        queue.push_back(low);
+        while let Some(pos) = queue.pop_front() {
+            let height = (heights[pos].unwrap() + 1).min(8);
+            for n in wall.neighbors4(pos) {
+                if !wall[n] && heights[n].is_none() {
+                    heights[n] = Some(height);
+                    queue.push_back(n);
+                }
+            }
+        }
+    }
+    heights.map(|h| h.unwrap_or(9)).to_string() + "\n"
+}
+
+/// A `size` by `size` grid of energy levels that synchronizes within 10
+/// steps. Every level is one of two adjacent values, so once the higher
+/// ones flash all the others are at 9 and the flash spreads to the whole
+/// grid.
+fn octopuses(rng: &mut StdRng, size: usize) -> String {
+    let low = rng.gen_range(0..=8);
+    let grid = Grid::from_fn(size, size, |_| low + u8::from(rng.gen_bool(0.5)));
+    grid.to_string() + "\n"
+}
+
+/// Lines that are either corrupted or incomplete. Incomplete lines never
+/// have more than 20 open chunks, so completion scores fit in a `u64`.
+fn brackets(rng: &mut StdRng, size: usize) -> String {
+    const PAIRS: [(char, char); 4] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')];
+    lines((0..size).map(|_| {
+        let length = rng.gen_range(10..100);
+        let corrupt_at = rng.gen_bool(0.5).then(|| rng.gen_range(1..length));
+        let mut line = String::new();
+        let mut open = Vec::new();
+        let mut corrupted = false;
+        for i in 0..length {
+            if Some(i) == corrupt_at && !open.is_empty() {
+                corrupted = true;
+                let expected = open.pop().unwrap();
+                let wrong = PAIRS.iter().map(|p| p.1).filter(|&c| c != expected);
+                line.push(wrong.collect::<Vec<_>>()[rng.gen_range(0..3)]);
+            } else if !open.is_empty() && (open.len() >= 20 || rng.gen_bool(0.45)) {
+                line.push(open.pop().unwrap());
+            } else {
+                let (o, c) = PAIRS[rng.gen_range(0..4)];
+                line.push(o);
+                open.push(c);
+            }
+        }
+        if open.is_empty() && !corrupted {
+            line.push('(');
+        }
+        line
+    }))
+}
+
+/// `size` caves besides start and end, about a third of them big. Big
+/// caves are never connected to each other, so the number of paths is
+/// finite.
+fn caves(rng: &mut StdRng, size: usize) -> String {
+    let name = |mut i: usize, big: bool| {
+        let base = if big { b'A' } else { b'a' };
+        let mut name = String::new();
+        loop {
+            name.insert(0, char::from(base + (i % 26) as u8));
+            i /= 26;
+            if i == 0 {
+                return name;
+            }
+        }
+    };
+    let mut caves = vec!["start".to_string(), "end".to_string()];
+    let mut big = vec![false, false];
+    for i in 0..size {
+        let is_big = i % 3 == 2;
+        caves.push(name(i, is_big));
+        big.push(is_big);
+    }
+    let mut edges = BTreeSet::new();
+    let connect = |a: usize, b: usize, edges: &mut BTreeSet<(usize, usize)>| {
+        if a != b && !(big[a] && big[b]) {
+            edges.insert((a.min(b), a.max(b)));
+        }
+    };
+    // A path from start to end through every cave, skipping big-big steps.
+    let mut order: Vec<usize> = (2..caves.len()).collect();
+    order.shuffle(rng);
+    let mut previous = 0;
+    for &cave in order.iter() {
+        if !(big[previous] && big[cave]) {
+            connect(previous, cave, &mut edges);
+            previous = cave;
+        }
+    }
+    connect(previous, 1, &mut edges);
+    for _ in 0..size / 2 {
+        let (a, b) = (rng.gen_range(2..caves.len()), rng.gen_range(0..caves.len()));
+        connect(a, b, &mut edges);
+    }
+    lines(
+        edges
+            .into_iter()
+            .map(|(a, b)| format!("{}-{}", caves[a], caves[b])),
+    )
+}
+
+/// `size` dots on a folded sheet, unfolded along 5 vertical and 5
+/// horizontal lines so that no dot ends up on a fold.
+fn transparent_paper(rng: &mut StdRng, size: usize) -> String {
+    let (mut width, mut height) = (40, 6);
+    let mut points: Vec<(usize, usize)> = (0..size)
+        .map(|_| (rng.gen_range(0..width), rng.gen_range(0..height)))
+        .collect();
+    let mut folds = vec!['x'; 5];
+    folds.extend(['y'; 5]);
+    folds.shuffle(rng);
+    let mut instructions = Vec::new();
+    for &axis in folds.iter() {
+        let line = if axis == 'x' { width } else { height };
+        for point in points.iter_mut() {
+            if rng.gen_bool(0.5) {
+                let coord = if axis == 'x' {
+                    &mut point.0
+                } else {
+                    &mut point.1
+                };
+                *coord = 2 * line - *coord;
+            }
+        }
+        if axis == 'x' {
+            width = 2 * width + 1;
+        } else {
+            height = 2 * height + 1;
+        }
+        instructions.push(format!("fold along {}={}", axis, line));
+    }
+    instructions.reverse();
+    let points: BTreeSet<_> = points.into_iter().collect();
+    lines(points.into_iter().map(|(x, y)| format!("{},{}", x, y))) + "\n" + &lines(instructions)
+}
+
+fn polymer(rng: &mut StdRng, size: usize) -> String {
+    let elements: Vec<char> = "BCFHKNOPSV".chars().collect();
+    let template: String = (0..size.max(2))
+        .map(|_| *elements.choose(rng).unwrap())
+        .collect();
+    let mut out = template + "\n\n";
+    for a in elements.iter() {
+        for b in elements.iter() {
+            out += &format!("{}{} -> {}\n", a, b, elements.choose(rng).unwrap());
+        }
+    }
+    out
+}
+
+/// A random BITS packet tree of about `size` packets. Products only have
+/// small literal operands, so the value fits in a `u64`.
+fn transmission(rng: &mut StdRng, size: usize) -> String {
+    fn push(bits: &mut Vec<bool>, value: u64, width: usize) {
+        bits.extend((0..width).rev().map(|i| value >> i & 1 == 1));
+    }
+
+    fn literal(rng: &mut StdRng, bits: &mut Vec<bool>, max: u64) {
+        push(bits, rng.gen_range(0..8), 3);
+        push(bits, 4, 3);
+        let value = rng.gen_range(0..=max);
+        let groups = (64 - value.leading_zeros() as usize).max(1).div_ceil(4);
+        for i in (0..groups).rev() {
+            push(bits, u64::from(i > 0), 1);
+            push(bits, value >> (4 * i) & 0xf, 4);
+        }
+    }
+
+    fn packet(rng: &mut StdRng, bits: &mut Vec<bool>, budget: &mut usize, root: bool) {
+        if *budget == 0 || (!root && rng.gen_bool(0.3)) {
+            *budget = budget.saturating_sub(1);
+            return literal(rng, bits, 1 << 20);
+        }
+        *budget -= 1;
+        let type_id = *[0, 1, 2, 3, 5, 6, 7].choose(rng).unwrap();
+        let count = match type_id {
+            5..=7 => 2,
+            1 => rng.gen_range(1..=4),
+            _ => rng.gen_range(1..=(*budget).clamp(1, 6)),
+        };
+        let mut operands = Vec::new();
+        for _ in 0..count {
+            if type_id == 1 {
+                literal(rng, &mut operands, 9);
+            } else {
+                packet(rng, &mut operands, budget, false);
+            }
+        }
+        push(bits, rng.gen_range(0..8), 3);
+        push(bits, type_id, 3);
+        if rng.gen_bool(0.5) && operands.len() < 1 << 15 {
+            push(bits, 0, 1);
+            push(bits, operands.len() as u64, 15);
+        } else {
+            push(bits, 1, 1);
+            push(bits, count as u64, 11);
+        }
+        bits.extend(operands);
+    }
+
+    let mut bits = Vec::new();
+    let mut budget = size;
+    packet(rng, &mut bits, &mut budget, true);
+    while bits.len() % 8 != 0 {
+        bits.push(false);
+    }
+    let hex: String = bits
+        .chunks(4)
+        .map(|nibble| {
+            let value = nibble.iter().fold(0, |acc, &bit| acc << 1 | u32::from(bit));
+            char::from_digit(value, 16).unwrap().to_ascii_uppercase()
+        })
+        .collect();
+    hex + "\n"
+}
+
+fn target_area(rng: &mut StdRng, size: usize) -> String {
+    let size = size.max(10) as i64;
+    let x_min = rng.gen_range(size..2 * size);
+    let x_max = x_min + rng.gen_range(size / 5..=size / 2);
+    let y_min = -rng.gen_range(size..2 * size);
+    let y_max = (y_min + rng.gen_range(size / 5..=size / 2)).min(-1);
+    format!(
+        "target area: x={}..{}, y={}..{}\n",
+        x_min, x_max, y_min, y_max
+    )
+}
+
+/// Reduced snailfish numbers: no pair nested in four others and no
+/// regular number above 9.
+fn snailfish(rng: &mut StdRng, size: usize) -> String {
+    fn element(rng: &mut StdRng, depth: usize) -> String {
+        if depth == 4 || (depth > 0 && rng.gen_bool(0.4)) {
+            rng.gen_range(0..10).to_string()
+        } else {
+            format!("[{},{}]", element(rng, depth + 1), element(rng, depth + 1))
+        }
+    }
+    lines((0..size).map(|_| element(rng, 0)))
+}
+
+/// The day 19 input `generate(19, size, seed)` gives, along with where
+/// each scanner is relative to scanner 0 and the rotation that turns its
+/// readings into scanner 0's orientation.
+pub fn scanner_layout(size: usize, seed: u64) -> (String, Vec<(Vec3<i32>, Rotation)>) {
+    scanners(&mut StdRng::seed_from_u64(seed), size.max(1))
+}
+
+/// `size` scanners, each placed within reach of an earlier one and rotated
+/// by a random one of the 24 orientations. Every scanner shares at least
+/// 12 beacons with the scanner it was placed next to.
+fn scanners(rng: &mut StdRng, size: usize) -> (String, Vec<(Vec3<i32>, Rotation)>) {
+    type Point = Vec3<i32>;
+    let near = |rng: &mut StdRng, center: Point, reach: i32| {
+        center
+            + Point::new(
+                rng.gen_range(-reach..=reach),
+                rng.gen_range(-reach..=reach),
+                rng.gen_range(-reach..=reach),
+            )
+    };
+    let mut positions = vec![Point::new(0, 0, 0)];
+    let mut beacons = BTreeSet::new();
+    for _ in 0..20 {
+        beacons.insert(near(rng, positions[0], 950));
+    }
+    for _ in 1..size {
+        let parent = positions[rng.gen_range(0..positions.len())];
+        let position = near(rng, parent, 600);
+        // Inside both scanners' range, with some margin.
+        let low = parent.zip(position).map(|(a, b)| a.max(b) - 950);
+        let high = parent.zip(position).map(|(a, b)| a.min(b) + 950);
+        for _ in 0..12 {
+            let shared = low.zip(high).map(|(l, h)| rng.gen_range(l..=h));
+            beacons.insert(shared);
+        }
+        for _ in 0..10 {
+            beacons.insert(near(rng, position, 950));
+        }
+        positions.push(position);
+    }
+
+    let rotations = Rotation::all();
+    let mut out = String::new();
+    let mut layout = Vec::new();
+    for (i, &position) in positions.iter().enumerate() {
+        let rotation = if i == 0 {
+            Rotation::IDENTITY
+        } else {
+            *rotations.choose(rng).unwrap()
+        };
+        let mut seen: Vec<Point> = beacons
+            .iter()
+            .map(|&b| b - position)
+            .filter(|b| b.to_array().iter().all(|c| c.abs() <= 1000))
+            .map(|b| rotation.apply(b))
+            .collect();
+        seen.shuffle(rng);
+        if i > 0 {
+            out += "\n";
+        }
+        out += &format!("--- scanner {} ---\n", i);
+        out += &lines(seen.iter().map(|b| format!("{},{},{}", b.x, b.y, b.z)));
+        layout.push((position, rotation.inverse()));
+    }
+    (out, layout)
+}
+
+/// A random enhancement algorithm that never lights up infinitely many
+/// pixels for good, and a `size` by `size` image.
+fn trench_map(rng: &mut StdRng, size: usize) -> String {
+    let pixel = |rng: &mut StdRng| if rng.gen_bool(0.5) { '#' } else { '.' };
+    let mut algorithm: Vec<char> = (0..512).map(|_| pixel(rng)).collect();
+    if algorithm[0] == '#' {
+        algorithm[511] = '.';
+    }
+    let mut out: String = algorithm.into_iter().collect();
+    out += "\n\n";
+    out + &lines((0..size).map(|_| (0..size).map(|_| pixel(rng)).collect()))
+}
+
+fn starting_positions(rng: &mut StdRng) -> String {
+    format!(
+        "Player 1 starting position: {}\nPlayer 2 starting position: {}\n",
+        rng.gen_range(1..=10),
+        rng.gen_range(1..=10)
+    )
+}
+
+/// `size` reboot steps, the first half inside the -50..50 region.
+fn reboot_steps(rng: &mut StdRng, size: usize) -> String {
+    lines((0..size).map(|i| {
+        let (limit, extent) = if i < size.div_ceil(2) {
+            (50, 30)
+        } else {
+            (100_000, 40_000)
+        };
+        let range = |rng: &mut StdRng| {
+            let low = rng.gen_range(-limit..=limit);
+            format!("{}..{}", low, (low + rng.gen_range(0..=extent)).min(limit))
+        };
+        let value = if i == 0 || rng.gen_bool(0.6) {
+            "on"
+        } else {
+            "off"
+        };
+        format!(
+            "{} x={},y={},z={}",
+            value,
+            range(rng),
+            range(rng),
+            range(rng)
+        )
+    }))
+}
+
+fn amphipods(rng: &mut StdRng) -> String {
+    let mut pods: Vec<char> = "AABBCCDD".chars().collect();
+    pods.shuffle(rng);
+    format!(
+        "#############\n#...........#\n###{}###\n  #{}#\n  #########\n",
+        join(pods[..4].iter(), "#"),
+        join(pods[4..].iter(), "#")
+    )
+}
@@ -0,0 +1,310 @@
+use crate::parse::{self, ParseError};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A position in a grid as `(row, column)`.
+pub type Pos = (usize, usize);
+
+const OFFSETS4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+const OFFSETS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A dense rectangular grid stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn(height: usize, width: usize, mut f: impl FnMut(Pos) -> T) -> Grid<T> {
+        let mut cells = Vec::with_capacity(width * height);
+        for row in 0..height {
+            for col in 0..width {
+                cells.push(f((row, col)));
+            }
+        }
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Builds a grid from rows, which must all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Grid<T>> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        Some(Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses one cell per character, where `cell` returns `None` for
+    /// characters that are not allowed.
+    pub fn parse_with(
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        Grid::parse_rows(input, expected, 0, |i, line, cells| {
+            for (j, c) in line.char_indices() {
+                let token = &line[j..j + c.len_utf8()];
+                cells.push(cell(c).ok_or_else(|| ParseError::at(i, line, token, expected))?);
+            }
+            Ok(())
+        })
+    }
+
+    /// Like `parse_with`, but works on single bytes without decoding
+    /// characters, for large ASCII grids.
+    pub fn parse_bytes_with(
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(u8) -> Option<T>,
+    ) -> Result<Grid<T>, ParseError> {
+        Grid::parse_rows(input, expected, input.len(), |i, line, cells| {
+            for (j, &b) in line.as_bytes().iter().enumerate() {
+                match cell(b) {
+                    Some(value) => cells.push(value),
+                    None => {
+                        let len = line[j..].chars().next().map_or(1, char::len_utf8);
+                        return Err(ParseError::at(i, line, &line[j..j + len], expected));
+                    }
+                }
+            }
+            Ok(())
+        })
+    }
+
+    /// Parses each line with `row`, which appends its cells, and checks that
+    /// every row has the same number of cells.
+    fn parse_rows(
+        input: &str,
+        expected: &str,
+        capacity: usize,
+        mut row: impl FnMut(usize, &str, &mut Vec<T>) -> Result<(), ParseError>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut width = None;
+        let mut cells = Vec::with_capacity(capacity);
+        let mut height = 0;
+        for (i, line) in parse::lines(input) {
+            let start = cells.len();
+            row(i, line, &mut cells)?;
+            let row_width = cells.len() - start;
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    let expected = format!("row of {} cells", width);
+                    return Err(ParseError::at(i, line, line, &expected));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) if width > 0 => Ok(Grid {
+                width,
+                height,
+                cells,
+            }),
+            _ => Err(ParseError::new(1, 1, expected, "")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, (row, col): Pos) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        if self.contains(pos) {
+            Some(&self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    /// Looks up a cell by signed coordinates, returning `None` outside the
+    /// grid.
+    pub fn get_signed(&self, row: isize, col: isize) -> Option<&T> {
+        if row < 0 || col < 0 {
+            return None;
+        }
+        self.get((row as usize, col as usize))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| (i / width, i % width))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn indexed_iter_mut(&mut self) -> impl Iterator<Item = (Pos, &mut T)> {
+        self.positions().zip(self.cells.iter_mut())
+    }
+
+    fn offset_positions(
+        &self,
+        (row, col): Pos,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Pos> {
+        let (height, width) = (self.height, self.width);
+        offsets.iter().filter_map(move |&(dr, dc)| {
+            let r = row.checked_add_signed(dr)?;
+            let c = col.checked_add_signed(dc)?;
+            if r < height && c < width {
+                Some((r, c))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Positions of the up to 4 orthogonal neighbors of `pos`.
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        self.offset_positions(pos, &OFFSETS4)
+    }
+
+    /// Positions of the up to 8 orthogonal and diagonal neighbors of `pos`.
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> {
+        self.offset_positions(pos, &OFFSETS8)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(col).step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |col| self.column(col))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(height: usize, width: usize, value: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl Grid<u8> {
+    /// Parses a block of single digits such as `2199943210`.
+    pub fn parse_digits(input: &str) -> Result<Grid<u8>, ParseError> {
+        Grid::parse_bytes_with(input, "digit", |b| match b {
+            b'0'..=b'9' => Some(b - b'0'),
+            _ => None,
+        })
+    }
+}
+
+impl Grid<char> {
+    pub fn parse_chars(input: &str) -> Result<Grid<char>, ParseError> {
+        Grid::parse_with(input, "character", Some)
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): Pos) -> &T {
+        assert!(
+            self.contains((row, col)),
+            "position {:?} outside grid",
+            (row, col)
+        );
+        &self.cells[row * self.width + col]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, (row, col): Pos) -> &mut T {
+        assert!(
+            self.contains((row, col)),
+            "position {:?} outside grid",
+            (row, col)
+        );
+        &mut self.cells[row * self.width + col]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
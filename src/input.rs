@@ -0,0 +1,119 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Default,
+    Test,
+    Path(PathBuf),
+    Stdin,
+}
+
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub name: String,
+    pub text: String,
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub day: u32,
+    pub tried: Vec<PathBuf>,
+    pub error: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.tried.is_empty() {
+            return write!(f, "No input for day {} on stdin: {}", self.day, self.error);
+        }
+        write!(f, "No input found for day {}, tried:", self.day)?;
+        for path in self.tried.iter() {
+            write!(f, "\n  {}", path.display())?;
+        }
+        write!(f, "\n({})", self.error)
+    }
+}
+
+impl std::error::Error for InputError {}
+
+fn input_dirs() -> Vec<PathBuf> {
+    let mut dirs = vec![PathBuf::from("inputs")];
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs");
+    if manifest_dir != dirs[0] {
+        dirs.push(manifest_dir);
+    }
+    dirs
+}
+
+/// The directory new inputs are written to: `inputs` in the current
+/// directory if there is one, otherwise the one next to the crate manifest.
+/// `read_input` looks there too.
+pub fn inputs_dir() -> PathBuf {
+    let mut dirs = input_dirs();
+    match dirs.iter().position(|dir| dir.is_dir()) {
+        Some(i) => dirs.swap_remove(i),
+        None => dirs.pop().unwrap(),
+    }
+}
+
+fn read_first(day: u32, paths: Vec<PathBuf>) -> Result<Input, InputError> {
+    let mut last_error = None;
+    for path in paths.iter() {
+        match fs::read_to_string(path) {
+            Ok(text) => {
+                return Ok(Input {
+                    name: path.display().to_string(),
+                    text,
+                })
+            }
+            Err(error) => last_error = Some(error),
+        }
+    }
+    Err(InputError {
+        day,
+        tried: paths,
+        error: last_error.unwrap(),
+    })
+}
+
+pub fn read_input(day: u32, source: &InputSource) -> Result<Input, InputError> {
+    match source {
+        InputSource::Default => read_first(
+            day,
+            input_dirs()
+                .into_iter()
+                .map(|dir| dir.join(format!("{}.txt", day)))
+                .collect(),
+        ),
+        InputSource::Test => read_first(
+            day,
+            input_dirs()
+                .into_iter()
+                .map(|dir| dir.join(format!("{}_test.txt", day)))
+                .collect(),
+        ),
+        InputSource::Path(path) => read_first(day, vec![path.clone()]),
+        InputSource::Stdin => {
+            let mut input = String::new();
+            match io::stdin().read_to_string(&mut input) {
+                Ok(0) => Err(InputError {
+                    day,
+                    tried: vec![],
+                    error: io::Error::new(io::ErrorKind::UnexpectedEof, "stdin is empty"),
+                }),
+                Ok(_) => Ok(Input {
+                    name: "<stdin>".to_string(),
+                    text: input,
+                }),
+                Err(error) => Err(InputError {
+                    day,
+                    tried: vec![],
+                    error,
+                }),
+            }
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use log::{LevelFilter, Log, Metadata, Record, SetLoggerError};
+use std::cmp::max;
+use std::collections::HashMap;
+
+/// Writes log records to stderr, keeping stdout free for answers. Each day
+/// logs under its own module, so `days` can raise the level for single days.
+pub struct Logger {
+    default: LevelFilter,
+    days: HashMap<u32, LevelFilter>,
+}
+
+fn day_of(target: &str) -> Option<u32> {
+    target
+        .rsplit("::")
+        .next()?
+        .strip_prefix("day")?
+        .parse()
+        .ok()
+}
+
+impl Logger {
+    pub fn new(default: LevelFilter, days: HashMap<u32, LevelFilter>) -> Logger {
+        Logger { default, days }
+    }
+
+    fn level_for(&self, target: &str) -> LevelFilter {
+        day_of(target)
+            .and_then(|day| self.days.get(&day).copied())
+            .unwrap_or(self.default)
+    }
+
+    pub fn init(self) -> Result<(), SetLoggerError> {
+        let max_level = self.days.values().copied().fold(self.default, max);
+        log::set_boxed_logger(Box::new(self))?;
+        log::set_max_level(max_level);
+        Ok(())
+    }
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= self.level_for(metadata.target())
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            let target = record.target().rsplit("::").next().unwrap_or_default();
+            eprintln!("[{} {}] {}", record.level(), target, record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
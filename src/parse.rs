@@ -0,0 +1,140 @@
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: &str, found: &str) -> ParseError {
+        ParseError {
+            file: None,
+            line,
+            column,
+            expected: expected.to_string(),
+            found: found.to_string(),
+        }
+    }
+
+    /// Creates an error pointing at `token`, which must be a slice of `line`.
+    /// Any other token is reported at the end of the line.
+    pub fn at(line_no: usize, line: &str, token: &str, expected: &str) -> ParseError {
+        ParseError::new(line_no, column(line, token), expected, token)
+    }
+
+    /// Moves an error produced while parsing `token` on its own to the
+    /// position of `token` within `line`.
+    pub fn within(mut self, line_no: usize, line: &str, token: &str) -> ParseError {
+        self.line = line_no;
+        self.column += column(line, token) - 1;
+        self
+    }
+
+    pub fn in_file(mut self, file: &str) -> ParseError {
+        self.file = Some(file.to_string());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let file = self.file.as_deref().unwrap_or("<input>");
+        write!(
+            f,
+            "{}:{}:{}: expected {}, found ",
+            file, self.line, self.column, self.expected
+        )?;
+        if self.found.is_empty() {
+            write!(f, "end of line")
+        } else {
+            write!(f, "{:?}", self.found)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+fn column(line: &str, token: &str) -> usize {
+    let start = line.as_ptr() as usize;
+    let pos = token.as_ptr() as usize;
+    if pos >= start && pos + token.len() <= start + line.len() {
+        pos - start + 1
+    } else {
+        line.len() + 1
+    }
+}
+
+/// Numbered lines of `input`, starting at 1.
+pub fn lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input.lines().enumerate().map(|(i, line)| (i + 1, line))
+}
+
+/// Parses `token`, a slice of `line`, reporting `expected` on failure.
+pub fn token<T: FromStr>(
+    line_no: usize,
+    line: &str,
+    token: &str,
+    expected: &str,
+) -> Result<T, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::at(line_no, line, token, expected))
+}
+
+/// Splits `text`, a slice of `line`, at `separator` into exactly `N` fields.
+pub fn fields<'a, const N: usize>(
+    line_no: usize,
+    line: &str,
+    text: &'a str,
+    separator: &str,
+) -> Result<[&'a str; N], ParseError> {
+    let mut result = [""; N];
+    let mut parts = text.split(separator);
+    for (i, field) in result.iter_mut().enumerate() {
+        *field = match parts.next() {
+            Some(part) => part,
+            None => {
+                let expected = format!("{} fields separated by {:?}", N, separator);
+                return Err(ParseError::at(
+                    line_no,
+                    line,
+                    &text[text.len()..],
+                    &expected,
+                ));
+            }
+        };
+        if i == N - 1 {
+            if let Some(extra) = parts.next() {
+                return Err(ParseError::at(line_no, line, extra, "end of line"));
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Removes `prefix` from the start of `line`.
+pub fn strip<'a>(
+    line_no: usize,
+    line: &'a str,
+    text: &'a str,
+    prefix: &str,
+) -> Result<&'a str, ParseError> {
+    text.strip_prefix(prefix).ok_or_else(|| {
+        let end = text.len().min(prefix.len());
+        let found = text.get(..end).unwrap_or(text);
+        ParseError::at(line_no, line, found, &format!("{:?}", prefix))
+    })
+}
+
+/// Returns the first line of `input`, which must be present.
+pub fn first_line<'a>(input: &'a str, expected: &str) -> Result<&'a str, ParseError> {
+    input
+        .lines()
+        .next()
+        .ok_or_else(|| ParseError::new(1, 1, expected, ""))
+}
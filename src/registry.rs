@@ -0,0 +1,131 @@
+use crate::parse::ParseError;
+use crate::*;
+use std::any::Any;
+use std::fmt;
+
+pub type Parsed = Box<dyn Any>;
+pub type Parser = fn(&str) -> Result<Parsed, ParseError>;
+pub type Solver = fn(&dyn Any) -> Result<String, String>;
+
+/// The result of a part, which is either printable or an error explaining
+/// why the input has no answer.
+pub trait Answer {
+    fn answer(self) -> Result<String, String>;
+}
+
+macro_rules! answer {
+    ($($t:ty),*) => {
+        $(impl Answer for $t {
+            fn answer(self) -> Result<String, String> {
+                Ok(self.to_string())
+            }
+        })*
+    };
+}
+
+answer!(i32, i64, u32, u64, u128, usize, String, num::BigUint);
+
+impl<T: Answer, E: fmt::Display> Answer for Result<T, E> {
+    fn answer(self) -> Result<String, String> {
+        self.map_err(|error| error.to_string())?.answer()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RunError {
+    Parse(ParseError),
+    Solve(String),
+}
+
+impl RunError {
+    pub fn in_file(self, file: &str) -> RunError {
+        match self {
+            RunError::Parse(error) => RunError::Parse(error.in_file(file)),
+            RunError::Solve(error) => RunError::Solve(format!("{}: {}", file, error)),
+        }
+    }
+}
+
+impl fmt::Display for RunError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RunError::Parse(error) => write!(f, "{}", error),
+            RunError::Solve(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for RunError {}
+
+impl From<ParseError> for RunError {
+    fn from(error: ParseError) -> RunError {
+        RunError::Parse(error)
+    }
+}
+
+pub struct Day {
+    pub day: u32,
+    pub parse: Parser,
+    pub parts: [Solver; 2],
+}
+
+impl Day {
+    pub fn run(&self, part: usize, input: &str) -> Result<String, RunError> {
+        let parsed = (self.parse)(input)?;
+        self.parts[part - 1](parsed.as_ref()).map_err(RunError::Solve)
+    }
+}
+
+/// Recovers the output of `parse` from its type-erased form.
+fn downcast<T: 'static>(_parse: impl Fn(&str) -> Result<T, ParseError>, parsed: &dyn Any) -> &T {
+    parsed.downcast_ref().unwrap()
+}
+
+macro_rules! day {
+    ($n:expr, $module:ident) => {
+        Day {
+            day: $n,
+            parse: |input| Ok(Box::new($module::parse(input)?)),
+            parts: [
+                |parsed| {
+                    let input = downcast($module::parse, parsed);
+                    $module::part1(input).answer()
+                },
+                |parsed| {
+                    let input = downcast($module::parse, parsed);
+                    $module::part2(input).answer()
+                },
+            ],
+        }
+    };
+}
+
+pub static DAYS: [Day; 23] = [
+    day!(1, day1),
+    day!(2, day2),
+    day!(3, day3),
+    day!(4, day4),
+    day!(5, day5),
+    day!(6, day6),
+    day!(7, day7),
+    day!(8, day8),
+    day!(9, day9),
+    day!(10, day10),
+    day!(11, day11),
+    day!(12, day12),
+    day!(13, day13),
+    day!(14, day14),
+    day!(15, day15),
+    day!(16, day16),
+    day!(17, day17),
+    day!(18, day18),
+    day!(19, day19),
+    day!(20, day20),
+    day!(21, day21),
+    day!(22, day22),
+    day!(23, day23),
+];
+
+pub fn get_day(day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
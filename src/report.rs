@@ -0,0 +1,50 @@
+use crate::input::Input;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::time::Duration;
+
+/// One answer together with where it came from and how long it took.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Record {
+    pub day: u32,
+    pub part: usize,
+    pub answer: String,
+    pub input: String,
+    pub input_sha256: String,
+    pub time_ns: u128,
+}
+
+impl Record {
+    pub fn new(day: u32, part: usize, answer: String, input: &Input, elapsed: Duration) -> Record {
+        Record {
+            day,
+            part,
+            answer,
+            input: input.name.clone(),
+            input_sha256: hex::encode(Sha256::digest(input.text.as_bytes())),
+            time_ns: elapsed.as_nanos(),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+
+    pub fn to_human(&self) -> String {
+        let elapsed = Duration::from_nanos(self.time_ns as u64);
+        if self.answer.contains('\n') {
+            format!(
+                "Day {} part {} ({:.2?}):\n{}",
+                self.day,
+                self.part,
+                elapsed,
+                self.answer.trim_end()
+            )
+        } else {
+            format!(
+                "Day {} part {}: {} ({:.2?})",
+                self.day, self.part, self.answer, elapsed
+            )
+        }
+    }
+}
@@ -0,0 +1,252 @@
+use crate::parse::ParseError;
+use num::PrimInt;
+
+/// A cursor over the bytes of an input that parses values in place, keeping
+/// track of the line and column for errors.
+#[derive(Debug, Clone)]
+pub struct Scanner<'a> {
+    text: &'a str,
+    bytes: &'a [u8],
+    pos: usize,
+    line: usize,
+    line_start: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(text: &'a str) -> Scanner<'a> {
+        Scanner::starting_at(text, 1)
+    }
+
+    /// A scanner over `text` that reports errors as if `text` began on line
+    /// `line` of the input.
+    pub fn starting_at(text: &'a str, line: usize) -> Scanner<'a> {
+        Scanner {
+            text,
+            bytes: text.as_bytes(),
+            pos: 0,
+            line,
+            line_start: 0,
+        }
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.pos >= self.bytes.len()
+    }
+
+    fn advance(&mut self) {
+        if self.peek() == Some(b'\n') {
+            self.line += 1;
+            self.line_start = self.pos + 1;
+        }
+        self.pos += 1;
+    }
+
+    /// Skips bytes while `skip` holds, returning how many were skipped.
+    pub fn skip_while(&mut self, mut skip: impl FnMut(u8) -> bool) -> usize {
+        let start = self.pos;
+        while self.peek().is_some_and(&mut skip) {
+            self.advance();
+        }
+        self.pos - start
+    }
+
+    pub fn skip_whitespace(&mut self) -> usize {
+        self.skip_while(|b| b.is_ascii_whitespace())
+    }
+
+    /// Consumes `expected`, which must come next.
+    pub fn expect(&mut self, expected: &str) -> Result<(), ParseError> {
+        if self.bytes[self.pos..].starts_with(expected.as_bytes()) {
+            for _ in 0..expected.len() {
+                self.advance();
+            }
+            Ok(())
+        } else {
+            Err(self.error(&format!("{:?}", expected)))
+        }
+    }
+
+    /// Returns the rest of the current line and moves to the next one.
+    pub fn rest_of_line(&mut self) -> &'a str {
+        let start = self.pos;
+        let end = self.bytes[start..]
+            .iter()
+            .position(|&b| b == b'\n')
+            .map_or(self.bytes.len(), |i| start + i);
+        self.pos = end;
+        if !self.is_done() {
+            self.advance();
+        }
+        self.text[start..end].trim_end_matches('\r')
+    }
+
+    /// Parses a decimal integer with an optional sign, failing on overflow.
+    pub fn integer<T: PrimInt>(&mut self, expected: &str) -> Result<T, ParseError> {
+        let start = self.pos;
+        let negative = self.peek() == Some(b'-') && T::min_value() < T::zero();
+        if negative || self.peek() == Some(b'+') {
+            self.pos += 1;
+        }
+        let ten = T::from(10).unwrap();
+        let mut value = T::zero();
+        let digits = self.pos;
+        while let Some(b @ b'0'..=b'9') = self.peek() {
+            let digit = T::from(b - b'0').unwrap();
+            let next = value.checked_mul(&ten).and_then(|v| {
+                if negative {
+                    v.checked_sub(&digit)
+                } else {
+                    v.checked_add(&digit)
+                }
+            });
+            match next {
+                Some(next) => value = next,
+                None => {
+                    self.pos = start;
+                    return Err(self.error(expected));
+                }
+            }
+            self.pos += 1;
+        }
+        if self.pos == digits {
+            self.pos = start;
+            return Err(self.error(expected));
+        }
+        Ok(value)
+    }
+
+    /// Parses integers up to the end of the input. Between two integers
+    /// there must be at least one byte from `separators`; separators and
+    /// whitespace at the start and end are ignored. A `\n` separator may be
+    /// preceded by `\r`.
+    pub fn integers<T: PrimInt>(
+        &mut self,
+        separators: &[u8],
+        expected: &str,
+    ) -> Result<Vec<T>, ParseError> {
+        let mut values = Vec::new();
+        self.skip_while(|b| separators.contains(&b) || b.is_ascii_whitespace());
+        while !self.is_done() {
+            values.push(self.integer(expected)?);
+            if separators.contains(&b'\n') && self.bytes[self.pos..].starts_with(b"\r\n") {
+                self.pos += 1;
+            }
+            let skipped = self.skip_while(|b| separators.contains(&b));
+            self.skip_whitespace();
+            if skipped == 0 && !self.is_done() {
+                let expected = format!("one of {:?}", String::from_utf8_lossy(separators));
+                return Err(self.error(&expected));
+            }
+        }
+        Ok(values)
+    }
+
+    /// An error for the token at the current position.
+    pub fn error(&self, expected: &str) -> ParseError {
+        let rest = &self.text[self.pos..];
+        let end = match rest.char_indices().next() {
+            None => 0,
+            Some((_, c)) if !c.is_alphanumeric() && c != '-' && c != '+' => c.len_utf8(),
+            Some(_) => rest
+                .find(|c: char| !c.is_alphanumeric() && c != '-' && c != '+')
+                .unwrap_or(rest.len()),
+        };
+        let found = rest[..end].trim_end_matches(['\n', '\r']);
+        ParseError::new(self.line, self.pos - self.line_start + 1, expected, found)
+    }
+}
+
+/// Parses a whole block of integers, see `Scanner::integers`. `line` is the
+/// line the block starts on.
+pub fn integers<T: PrimInt>(
+    text: &str,
+    line: usize,
+    separators: &[u8],
+    expected: &str,
+) -> Result<Vec<T>, ParseError> {
+    Scanner::starting_at(text, line).integers(separators, expected)
+}
+
+/// Splits `input` at blank lines into sections, each with the number of its
+/// first line. Runs of blank lines count as one separator.
+pub fn sections(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    let bytes = input.as_bytes();
+    let mut pos = 0;
+    let mut line = 1;
+    std::iter::from_fn(move || {
+        // Skip blank lines before the section.
+        loop {
+            let end = bytes[pos..]
+                .iter()
+                .position(|&b| b == b'\n')
+                .map(|i| pos + i);
+            match end {
+                Some(end) if input[pos..end].trim().is_empty() => {
+                    pos = end + 1;
+                    line += 1;
+                }
+                None if input[pos..].trim().is_empty() => return None,
+                _ => break,
+            }
+        }
+        let (start, first_line) = (pos, line);
+        while pos < bytes.len() {
+            let end = bytes[pos..]
+                .iter()
+                .position(|&b| b == b'\n')
+                .map_or(bytes.len(), |i| pos + i);
+            if input[pos..end].trim().is_empty() {
+                break;
+            }
+            pos = (end + 1).min(bytes.len());
+            line += 1;
+        }
+        Some((first_line, input[start..pos].trim_end()))
+    })
+}
+
+/// A `key -> value` line, borrowed from the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rule<'a> {
+    pub line: usize,
+    pub text: &'a str,
+    pub key: &'a str,
+    pub value: &'a str,
+}
+
+/// Parses one `key <arrow> value` rule per line, e.g. `CH -> B`, without
+/// copying the keys or values. `line` is the line `text` starts on.
+pub fn rules<'a>(text: &'a str, line: usize, arrow: &str) -> Result<Vec<Rule<'a>>, ParseError> {
+    let mut rules = Vec::new();
+    for (i, text) in text.lines().enumerate() {
+        let text = text.trim_end_matches('\r');
+        if text.is_empty() {
+            continue;
+        }
+        let line = line + i;
+        let (key, value) = text.split_once(arrow).ok_or_else(|| {
+            ParseError::at(line, text, &text[text.len()..], &format!("{:?}", arrow))
+        })?;
+        if key.is_empty() {
+            return Err(ParseError::at(line, text, text, "key"));
+        }
+        if value.is_empty() {
+            return Err(ParseError::at(line, text, value, "value"));
+        }
+        rules.push(Rule {
+            line,
+            text,
+            key,
+            value,
+        });
+    }
+    Ok(rules)
+}
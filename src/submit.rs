@@ -0,0 +1,246 @@
+use crate::fetch::{self, BASE_URL, YEAR};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+use strum_macros::{Display, EnumString};
+
+/// The verdicts worth remembering for an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, EnumString)]
+#[strum(serialize_all = "kebab-case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+/// What the server said about a submission.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Response {
+    Verdict(Verdict),
+    /// Submitted too soon after the last wrong answer, with the wait time
+    /// if the server gave one.
+    TooSoon(Option<String>),
+    /// The part is already solved or not unlocked yet.
+    WrongLevel,
+    Unknown(String),
+}
+
+impl Response {
+    pub fn parse(page: &str) -> Response {
+        let article = match (page.find("<article"), page.find("</article>")) {
+            (Some(start), Some(end)) if start < end => &page[start..end],
+            _ => page,
+        };
+        if article.contains("That's the right answer") {
+            Response::Verdict(Verdict::Correct)
+        } else if article.contains("your answer is too high") {
+            Response::Verdict(Verdict::TooHigh)
+        } else if article.contains("your answer is too low") {
+            Response::Verdict(Verdict::TooLow)
+        } else if article.contains("That's not the right answer") {
+            Response::Verdict(Verdict::Wrong)
+        } else if article.contains("You gave an answer too recently") {
+            let wait = article
+                .split_once("You have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .map(|(wait, _)| wait.to_string());
+            Response::TooSoon(wait)
+        } else if article.contains("You don't seem to be solving the right level") {
+            Response::WrongLevel
+        } else {
+            Response::Unknown(article.to_string())
+        }
+    }
+}
+
+impl fmt::Display for Response {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Response::Verdict(Verdict::Correct) => write!(f, "correct"),
+            Response::Verdict(Verdict::TooHigh) => write!(f, "wrong, too high"),
+            Response::Verdict(Verdict::TooLow) => write!(f, "wrong, too low"),
+            Response::Verdict(Verdict::Wrong) => write!(f, "wrong"),
+            Response::TooSoon(Some(wait)) => write!(f, "too soon, wait {}", wait),
+            Response::TooSoon(None) => write!(f, "too soon"),
+            Response::WrongLevel => write!(f, "already solved or locked"),
+            Response::Unknown(_) => write!(f, "unrecognized response"),
+        }
+    }
+}
+
+/// What the ledger knows about an answer before it is submitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    /// Nothing rules the answer out.
+    Unknown,
+    /// The answer was submitted before.
+    Submitted(Verdict),
+    /// Another answer was already accepted.
+    Solved(String),
+    /// The answer is outside the range left by earlier too high/too low
+    /// verdicts, given as exclusive bounds.
+    OutOfBounds(Option<i128>, Option<i128>),
+}
+
+/// Submitted answers and their verdicts, stored as one
+/// `day part verdict answer` line per submission.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Ledger {
+    pub entries: BTreeMap<(u32, usize), Vec<(String, Verdict)>>,
+}
+
+impl Ledger {
+    pub fn load(path: &Path) -> io::Result<Ledger> {
+        let mut ledger = Ledger::default();
+        for (i, line) in fs::read_to_string(path)?.lines().enumerate() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let entry = match fields.as_slice() {
+                [] => continue,
+                [day, part, verdict, answer] => {
+                    match (day.parse(), part.parse(), Verdict::from_str(verdict)) {
+                        (Ok(day), Ok(part @ 1..=2), Ok(verdict)) => {
+                            Some((day, part, verdict, answer))
+                        }
+                        _ => None,
+                    }
+                }
+                _ => None,
+            };
+            match entry {
+                Some((day, part, verdict, answer)) => ledger.record(day, part, answer, verdict),
+                None => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("{}:{}: malformed ledger entry", path.display(), i + 1),
+                    ))
+                }
+            }
+        }
+        Ok(ledger)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut out = String::new();
+        for ((day, part), entries) in self.entries.iter() {
+            for (answer, verdict) in entries.iter() {
+                out += &format!("{} {} {} {}\n", day, part, verdict, answer);
+            }
+        }
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, out)
+    }
+
+    pub fn record(&mut self, day: u32, part: usize, answer: &str, verdict: Verdict) {
+        self.entries
+            .entry((day, part))
+            .or_default()
+            .push((answer.to_string(), verdict));
+    }
+
+    /// The exclusive range the answer must lie in, from the highest too low
+    /// and the lowest too high numeric guess.
+    pub fn bounds(&self, day: u32, part: usize) -> (Option<i128>, Option<i128>) {
+        let entries = self.entries.get(&(day, part)).map_or(&[][..], |e| e);
+        let numbers = |wanted: Verdict| {
+            entries
+                .iter()
+                .filter(move |(_, verdict)| *verdict == wanted)
+                .filter_map(|(answer, _)| answer.parse::<i128>().ok())
+        };
+        (
+            numbers(Verdict::TooLow).max(),
+            numbers(Verdict::TooHigh).min(),
+        )
+    }
+
+    pub fn check(&self, day: u32, part: usize, answer: &str) -> Check {
+        let entries = self.entries.get(&(day, part)).map_or(&[][..], |e| e);
+        if let Some((_, verdict)) = entries.iter().find(|(a, _)| a == answer) {
+            return Check::Submitted(*verdict);
+        }
+        if let Some((correct, _)) = entries.iter().find(|(_, v)| *v == Verdict::Correct) {
+            return Check::Solved(correct.clone());
+        }
+        let (low, high) = self.bounds(day, part);
+        match answer.parse::<i128>() {
+            Ok(n) if low.is_some_and(|low| n <= low) || high.is_some_and(|high| n >= high) => {
+                Check::OutOfBounds(low, high)
+            }
+            _ => Check::Unknown,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    NoSession,
+    Status(u16),
+    Network(String),
+}
+
+impl fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SubmitError::NoSession => write!(f, "AOC_SESSION is not set"),
+            SubmitError::Status(status) => write!(f, "Submission failed with status {}", status),
+            SubmitError::Network(error) => write!(f, "Could not submit answer: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+/// Posts answers to the puzzle server, which can be pointed at a local mock
+/// with `with_base_url`.
+pub struct Submitter {
+    base_url: String,
+    session: Option<String>,
+    agent: ureq::Agent,
+}
+
+impl Submitter {
+    pub fn new(session: Option<String>) -> Submitter {
+        Submitter {
+            base_url: BASE_URL.to_string(),
+            session,
+            agent: fetch::agent(),
+        }
+    }
+
+    /// Takes the session token from the `AOC_SESSION` environment variable.
+    pub fn from_env() -> Submitter {
+        Submitter::new(fetch::session_from_env())
+    }
+
+    pub fn with_base_url(mut self, base_url: &str) -> Submitter {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn url(&self, day: u32) -> String {
+        format!("{}/{}/day/{}/answer", self.base_url, YEAR, day)
+    }
+
+    pub fn submit(&self, day: u32, part: usize, answer: &str) -> Result<Response, SubmitError> {
+        let session = self.session.as_ref().ok_or(SubmitError::NoSession)?;
+        let response = self
+            .agent
+            .post(&self.url(day))
+            .set("Cookie", &format!("session={}", session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map(|page| Response::parse(&page))
+                .map_err(|error| SubmitError::Network(error.to_string())),
+            Err(ureq::Error::Status(status, _)) => Err(SubmitError::Status(status)),
+            Err(ureq::Error::Transport(error)) => Err(SubmitError::Network(error.to_string())),
+        }
+    }
+}
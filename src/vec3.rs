@@ -0,0 +1,229 @@
+use num::{Signed, Zero};
+use std::collections::HashSet;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+use std::sync::OnceLock;
+
+#[derive(Debug, Default, PartialEq, Eq, Hash, Copy, Clone, PartialOrd, Ord)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Vec3<T> {
+    pub fn new(x: T, y: T, z: T) -> Vec3<T> {
+        Vec3 { x, y, z }
+    }
+
+    pub fn from_array([x, y, z]: [T; 3]) -> Vec3<T> {
+        Vec3 { x, y, z }
+    }
+
+    pub fn to_array(self) -> [T; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> Vec3<U> {
+        Vec3 {
+            x: f(self.x),
+            y: f(self.y),
+            z: f(self.z),
+        }
+    }
+
+    pub fn zip<U>(self, other: Vec3<U>) -> Vec3<(T, U)> {
+        Vec3 {
+            x: (self.x, other.x),
+            y: (self.y, other.y),
+            z: (self.z, other.z),
+        }
+    }
+}
+
+impl<T: Signed + Copy> Vec3<T> {
+    pub fn manhattan(&self) -> T {
+        self.x.abs() + self.y.abs() + self.z.abs()
+    }
+
+    pub fn manhattan_to(&self, other: &Vec3<T>) -> T {
+        (*self - *other).manhattan()
+    }
+}
+
+impl<T> Index<usize> for Vec3<T> {
+    type Output = T;
+
+    fn index(&self, axis: usize) -> &T {
+        match axis {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("invalid axis {}", axis),
+        }
+    }
+}
+
+impl<T> IndexMut<usize> for Vec3<T> {
+    fn index_mut(&mut self, axis: usize) -> &mut T {
+        match axis {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("invalid axis {}", axis),
+        }
+    }
+}
+
+impl<T: Zero> Zero for Vec3<T> {
+    fn zero() -> Vec3<T> {
+        Vec3::new(T::zero(), T::zero(), T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.x.is_zero() && self.y.is_zero() && self.z.is_zero()
+    }
+}
+
+impl<T: Add<Output = T>> Add for Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn add(self, other: Vec3<T>) -> Vec3<T> {
+        Vec3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn sub(self, other: Vec3<T>) -> Vec3<T> {
+        Vec3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn neg(self) -> Vec3<T> {
+        Vec3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Vec3<T> {
+    type Output = Vec3<T>;
+
+    fn mul(self, factor: T) -> Vec3<T> {
+        Vec3::new(self.x * factor, self.y * factor, self.z * factor)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Vec3<T> {
+    fn add_assign(&mut self, other: Vec3<T>) {
+        self.x += other.x;
+        self.y += other.y;
+        self.z += other.z;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Vec3<T> {
+    fn sub_assign(&mut self, other: Vec3<T>) {
+        self.x -= other.x;
+        self.y -= other.y;
+        self.z -= other.z;
+    }
+}
+
+/// A rotation by multiples of 90 degrees, stored as a 3x3 matrix with a
+/// single ±1 per row and column.
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub struct Rotation {
+    matrix: [[i8; 3]; 3],
+}
+
+impl Rotation {
+    pub const IDENTITY: Rotation = Rotation {
+        matrix: [[1, 0, 0], [0, 1, 0], [0, 0, 1]],
+    };
+
+    /// Quarter turn around the x axis: y -> z, z -> -y.
+    pub const QUARTER_X: Rotation = Rotation {
+        matrix: [[1, 0, 0], [0, 0, -1], [0, 1, 0]],
+    };
+
+    /// Quarter turn around the y axis: z -> x, x -> -z.
+    pub const QUARTER_Y: Rotation = Rotation {
+        matrix: [[0, 0, 1], [0, 1, 0], [-1, 0, 0]],
+    };
+
+    pub fn matrix(&self) -> [[i8; 3]; 3] {
+        self.matrix
+    }
+
+    pub fn apply<T: Neg<Output = T> + Zero + Copy>(&self, v: Vec3<T>) -> Vec3<T> {
+        let v = v.to_array();
+        let row = |r: &[i8; 3]| {
+            r.iter()
+                .zip(v.iter())
+                .fold(T::zero(), |acc, (&m, &c)| match m {
+                    1 => acc + c,
+                    -1 => acc + -c,
+                    _ => acc,
+                })
+        };
+        Vec3::new(
+            row(&self.matrix[0]),
+            row(&self.matrix[1]),
+            row(&self.matrix[2]),
+        )
+    }
+
+    /// The rotation that applies `other` first and then `self`.
+    pub fn compose(&self, other: &Rotation) -> Rotation {
+        let mut matrix = [[0; 3]; 3];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            for (j, cell) in row.iter_mut().enumerate() {
+                *cell = (0..3).map(|k| self.matrix[i][k] * other.matrix[k][j]).sum();
+            }
+        }
+        Rotation { matrix }
+    }
+
+    pub fn inverse(&self) -> Rotation {
+        let mut matrix = [[0; 3]; 3];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            for (j, cell) in row.iter_mut().enumerate() {
+                *cell = self.matrix[j][i];
+            }
+        }
+        Rotation { matrix }
+    }
+
+    pub fn determinant(&self) -> i32 {
+        let m = self.matrix.map(|row| row.map(i32::from));
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+
+    /// All 24 orientations of a cube, generated by closing the identity
+    /// under the two quarter turns. The first element is the identity.
+    pub fn all() -> &'static [Rotation] {
+        static ROTATIONS: OnceLock<Vec<Rotation>> = OnceLock::new();
+        ROTATIONS.get_or_init(|| {
+            let mut rotations = vec![Rotation::IDENTITY];
+            let mut seen = HashSet::from([Rotation::IDENTITY]);
+            let mut i = 0;
+            while i < rotations.len() {
+                for generator in [Rotation::QUARTER_X, Rotation::QUARTER_Y] {
+                    let next = generator.compose(&rotations[i]);
+                    if seen.insert(next) {
+                        rotations.push(next);
+                    }
+                }
+                i += 1;
+            }
+            assert_eq!(rotations.len(), 24);
+            assert!(rotations.iter().all(|r| r.determinant() == 1));
+            rotations
+        })
+    }
+}
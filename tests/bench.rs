@@ -0,0 +1,61 @@
+use advent_of_code_2021::bench::{measure, Baseline, Change, Limits, Timing};
+use advent_of_code_2021::input::{read_input, InputSource};
+use advent_of_code_2021::registry::get_day;
+use std::env;
+use std::time::Duration;
+
+const LIMITS: Limits = Limits {
+    min_runs: 1,
+    max_runs: 3,
+    budget: Duration::from_millis(100),
+};
+
+#[test]
+fn baseline_round_trip() {
+    let mut baseline = Baseline::default();
+    baseline.timings.insert(
+        (5, 2),
+        Timing {
+            parse: Duration::from_nanos(1500),
+            solve: Duration::from_millis(12),
+        },
+    );
+    let path = env::temp_dir().join(format!("aoc-baseline-{}.txt", std::process::id()));
+    baseline.save(&path).unwrap();
+    let loaded = Baseline::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded, baseline);
+}
+
+#[test]
+fn change_uses_threshold() {
+    let ms = Duration::from_millis;
+    assert_eq!(Change::between(None, ms(10), 0.1), Change::New);
+    assert!(matches!(
+        Change::between(Some(ms(100)), ms(105), 0.1),
+        Change::Unchanged(_)
+    ));
+    assert!(Change::between(Some(ms(100)), ms(150), 0.1).is_regression());
+    assert!(matches!(
+        Change::between(Some(ms(100)), ms(50), 0.1),
+        Change::Faster(_)
+    ));
+}
+
+#[test]
+fn measure_times_both_parts() {
+    let input = read_input(5, &InputSource::Test).unwrap();
+    let timings = measure(get_day(5).unwrap(), &input.text, &LIMITS).unwrap();
+    for timing in timings.iter() {
+        let timing = timing.as_ref().unwrap();
+        assert!(!timing.parse.is_zero());
+        assert!(!timing.solve.is_zero());
+    }
+}
+
+#[test]
+fn measure_reports_failing_parts() {
+    let timings = measure(get_day(9).unwrap(), "09\n90\n", &LIMITS).unwrap();
+    assert!(timings[0].is_ok());
+    assert_eq!(timings[1], Err("Only 2 basin(s), need 3".to_string()));
+}
@@ -0,0 +1,128 @@
+use advent_of_code_2021::day4::{self, Bingo, BingoBoard, Replay, Rules, Win};
+use advent_of_code_2021::parse::ParseError;
+
+const EXAMPLE: &str = "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7
+";
+
+#[test]
+fn ranks_every_board() {
+    let bingo = day4::parse(EXAMPLE).unwrap();
+    let ranking = bingo.play(Rules::default());
+    assert_eq!(
+        ranking.wins,
+        vec![
+            Win {
+                board: 2,
+                turn: 11,
+                draw: 24,
+                score: 4512
+            },
+            Win {
+                board: 0,
+                turn: 13,
+                draw: 16,
+                score: 137 * 16
+            },
+            Win {
+                board: 1,
+                turn: 14,
+                draw: 13,
+                score: 1924
+            },
+        ]
+    );
+    assert!(ranking.losers.is_empty());
+    assert_eq!(day4::part1(&bingo), Ok(4512));
+    assert_eq!(day4::part2(&bingo), Ok(1924));
+}
+
+#[test]
+fn diagonals_and_large_numbers() {
+    let board = BingoBoard::new(vec![100_000, 1, 2, 3, 200_000, 4, 5, 6, 300_000]).unwrap();
+    assert_eq!(board.size(), 3);
+    let bingo = Bingo {
+        draws: vec![300_000, 200_000, 100_000, 3, 4],
+        boards: vec![board],
+    };
+
+    let ranking = bingo.play(Rules { diagonals: true });
+    assert_eq!(
+        ranking.first(),
+        Some(&Win {
+            board: 0,
+            turn: 2,
+            draw: 100_000,
+            score: 100_000 * 21
+        })
+    );
+
+    let ranking = bingo.play(Rules::default());
+    assert_eq!(ranking.first().unwrap().turn, 4);
+
+    let bingo = Bingo {
+        draws: vec![1, 2],
+        ..bingo
+    };
+    assert_eq!(bingo.play(Rules::default()).losers, vec![0]);
+    assert!(day4::part1(&bingo).is_err());
+    assert!(BingoBoard::new(vec![1, 2, 3]).is_none());
+}
+
+#[test]
+fn replays_and_jumps_to_draws() {
+    colored::control::set_override(false);
+    let bingo = day4::parse(EXAMPLE).unwrap();
+    let mut replay = Replay::new(&bingo, Rules::default());
+    replay.seek(11);
+    assert!(replay.wins().iter().all(|win| win.is_none()));
+    let wins = replay.step().unwrap();
+    assert_eq!(wins.len(), 1);
+    assert_eq!(replay.wins()[2], Some(wins[0]));
+    assert!(replay.boards()[2].is_marked(0, 0));
+
+    let rendered = replay.render();
+    assert!(rendered.starts_with("Draw 12/27: 24\n"));
+    assert!(rendered.contains("\nBoard 3 won on draw 12 with 24, score 4512\n14 21 17 24  4\n"));
+
+    replay.seek(5);
+    assert_eq!(replay.turn(), 5);
+    assert!(replay.wins().iter().all(|win| win.is_none()));
+    assert!(!replay.boards()[2].is_marked(0, 0));
+    replay.seek(100);
+    assert_eq!(replay.turn(), 27);
+    assert_eq!(replay.step(), None);
+}
+
+#[test]
+fn rejects_ragged_boards() {
+    assert_eq!(
+        day4::parse("1,2\n\n1 2\n3\n").unwrap_err(),
+        ParseError::new(4, 1, "row of 2 numbers", "3")
+    );
+    assert_eq!(
+        day4::parse("1,2\n\n1 2\n3 4\n5 6\n").unwrap_err(),
+        ParseError::new(5, 1, "end of board", "5 6")
+    );
+    assert_eq!(
+        day4::parse("1,2\n\n1 2 3\n4 5 6\n\n").unwrap_err(),
+        ParseError::new(5, 1, "row of 3 numbers", "")
+    );
+}
@@ -0,0 +1,59 @@
+use std::sync::{Arc, Mutex};
+use std::thread;
+use tiny_http::{Response, Server};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Request {
+    pub method: String,
+    pub url: String,
+    pub cookie: Option<String>,
+    pub body: String,
+}
+
+/// A stand-in for the puzzle server that answers every request with a fixed
+/// status and body and records what it was asked for.
+pub struct MockServer {
+    server: Arc<Server>,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl MockServer {
+    pub fn start(status: u16, body: &'static str) -> MockServer {
+        let server = Arc::new(Server::http("127.0.0.1:0").unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let (s, r) = (server.clone(), requests.clone());
+        thread::spawn(move || {
+            for mut request in s.incoming_requests() {
+                let cookie = request
+                    .headers()
+                    .iter()
+                    .find(|h| h.field.equiv("Cookie"))
+                    .map(|h| h.value.to_string());
+                let mut content = String::new();
+                let _ = request.as_reader().read_to_string(&mut content);
+                r.lock().unwrap().push(Request {
+                    method: request.method().to_string(),
+                    url: request.url().to_string(),
+                    cookie,
+                    body: content,
+                });
+                let _ = request.respond(Response::from_string(body).with_status_code(status));
+            }
+        });
+        MockServer { server, requests }
+    }
+
+    pub fn url(&self) -> String {
+        format!("http://{}", self.server.server_addr().to_ip().unwrap())
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.server.unblock();
+    }
+}
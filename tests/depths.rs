@@ -0,0 +1,62 @@
+use advent_of_code_2021::day1::{self, Changes, Window};
+
+const READINGS: [i64; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+#[test]
+fn counts_changes_and_runs() {
+    assert_eq!(
+        day1::changes(READINGS),
+        Changes {
+            increases: 7,
+            decreases: 2,
+            unchanged: 0,
+            longest_increasing: 4,
+            longest_decreasing: 2,
+        }
+    );
+    assert_eq!(
+        day1::changes([3, 3, 2, 1, 1]),
+        Changes {
+            increases: 0,
+            decreases: 2,
+            unchanged: 2,
+            longest_increasing: 1,
+            longest_decreasing: 3,
+        }
+    );
+    assert_eq!(day1::changes([]), Changes::default());
+}
+
+#[test]
+fn windows_over_stream() {
+    let windows: Vec<Window> = day1::windows(READINGS, 3).collect();
+    assert_eq!(windows.len(), 8);
+    assert_eq!(
+        windows[3],
+        Window {
+            sum: 617,
+            min: 200,
+            max: 210,
+            len: 3
+        }
+    );
+    assert_eq!(windows[3].mean(), 617.0 / 3.0);
+    let sums: Vec<i64> = windows.iter().map(|w| w.sum).collect();
+    assert_eq!(sums, vec![607, 618, 618, 617, 647, 716, 769, 792]);
+    assert_eq!(day1::changes(sums).increases, 5);
+
+    assert_eq!(day1::windows(READINGS, 11).count(), 0);
+    assert_eq!(
+        day1::windows(1..=1_000_000, 1000).last().unwrap().min,
+        999_001
+    );
+}
+
+#[test]
+fn parses_crlf_lines() {
+    assert_eq!(
+        day1::parse("199\r\n200\r\n208\r\n"),
+        Ok(READINGS[..3].to_vec())
+    );
+    assert_eq!(day1::parse("199\r\n200"), Ok(READINGS[..2].to_vec()));
+}
@@ -0,0 +1,98 @@
+use advent_of_code_2021::day3::{self, Diagnostics, Rating, RatingError, Report};
+use advent_of_code_2021::parse::ParseError;
+use advent_of_code_2021::registry::{get_day, RunError};
+
+const EXAMPLE: &str =
+    "00100\n11110\n10110\n10111\n10101\n01111\n00111\n11100\n10000\n11001\n00010\n01010\n";
+
+#[test]
+fn infers_width() {
+    let report = day3::parse(EXAMPLE).unwrap();
+    assert_eq!(report.width, 5);
+    assert_eq!(
+        day3::analyze(&report),
+        Diagnostics {
+            gamma: 22,
+            epsilon: 9,
+            oxygen_generator: Ok(23),
+            co2_scrubber: Ok(10),
+        }
+    );
+
+    let wide = format!(
+        "{}\n{}\n{}\n",
+        "1".repeat(64),
+        "0".repeat(64),
+        "10".repeat(32)
+    );
+    let report = day3::parse(&wide).unwrap();
+    assert_eq!(report.width, 64);
+    let diagnostics = day3::analyze(&report);
+    assert_eq!(diagnostics.gamma, 0xaaaa_aaaa_aaaa_aaaa);
+    assert_eq!(diagnostics.epsilon, 0x5555_5555_5555_5555);
+    assert_eq!(diagnostics.oxygen_generator, Ok(u64::MAX));
+    assert_eq!(diagnostics.co2_scrubber, Ok(0));
+    assert_eq!(
+        day3::part1(&report),
+        0xaaaa_aaaa_aaaa_aaaa * 0x5555_5555_5555_5555
+    );
+}
+
+#[test]
+fn rejects_bad_reports() {
+    assert_eq!(
+        day3::parse("0101\n011\n"),
+        Err(ParseError::new(2, 1, "4-bit binary number", "011"))
+    );
+    assert_eq!(
+        day3::parse("01\n02\n"),
+        Err(ParseError::new(2, 2, "binary digit", "2"))
+    );
+    assert_eq!(
+        day3::parse("+101\n0110\n"),
+        Err(ParseError::new(1, 1, "binary digit", "+"))
+    );
+    assert_eq!(
+        day3::parse("10é\n"),
+        Err(ParseError::new(1, 3, "binary digit", "é"))
+    );
+    let long = "1".repeat(65);
+    assert_eq!(
+        day3::parse(&long),
+        Err(ParseError::new(
+            1,
+            1,
+            "binary number of 1 to 64 bits",
+            &long
+        ))
+    );
+}
+
+#[test]
+fn reports_missing_ratings() {
+    let report = Report {
+        width: 3,
+        numbers: vec![0b101, 0b111],
+    };
+    let diagnostics = day3::analyze(&report);
+    assert_eq!(diagnostics.oxygen_generator, Ok(0b111));
+    assert_eq!(
+        diagnostics.co2_scrubber,
+        Err(RatingError::NoneLeft(Rating::Co2Scrubber, 2))
+    );
+
+    let report = Report {
+        width: 2,
+        numbers: vec![0b01, 0b01, 0b10],
+    };
+    assert_eq!(
+        day3::part2(&report),
+        Err(RatingError::Ambiguous(Rating::OxygenGenerator, 2))
+    );
+    assert_eq!(
+        get_day(3).unwrap().run(2, "01\n01\n10\n"),
+        Err(RunError::Solve(
+            "2 numbers are left for the oxygen generator rating".to_string()
+        ))
+    );
+}
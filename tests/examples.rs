@@ -0,0 +1,92 @@
+use advent_of_code_2021::examples::{extract, Answers};
+use std::env;
+
+const HTML: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1: Sonar Sweep ---</h2>
+<p>For example, suppose you had the following report:</p>
+<pre><code>199
+200
+208
+</code></pre>
+<p>In this example, there are <em>7</em> measurements that are larger than
+the previous measurement. So the answer is <code><em>7</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>1754</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>199  A
+200  A B
+</code></pre>
+<p>Compare <code>A &amp; B</code>: there are <em><code>5</code></em> sums
+that are larger than the previous sum.</p>
+</article>
+</main>"#;
+
+const MARKDOWN: &str = "## --- Day 5: Hydrothermal Venture ---
+
+```
+0,9 -> 5,9
+8,0 -> 0,8
+```
+
+Use `x1,y1 -> x2,y2`: at how many points do at least two lines overlap? \
+In the above example, this is anywhere in the diagram with a `2` or larger \
+- a total of **`5`** points.
+
+## --- Part Two ---
+
+Consider all of the lines. In the above example, this is now `*12*` points.
+";
+
+#[test]
+fn extracts_from_html() {
+    let examples = extract(HTML);
+    assert_eq!(
+        examples.blocks,
+        vec!["199\n200\n208\n", "199  A\n200  A B\n"]
+    );
+    assert_eq!(
+        examples.answers,
+        [Some("7".to_string()), Some("5".to_string())]
+    );
+}
+
+#[test]
+fn takes_last_answer_in_document_order() {
+    let page = "<article><p>First <em><code>3</code></em>, \
+                then <code><em>4</em></code>.</p></article>";
+    assert_eq!(extract(page).answers[0], Some("4".to_string()));
+}
+
+#[test]
+fn extracts_from_markdown() {
+    let examples = extract(MARKDOWN);
+    assert_eq!(examples.blocks, vec!["0,9 -> 5,9\n8,0 -> 0,8\n"]);
+    assert_eq!(
+        examples.answers,
+        [Some("5".to_string()), Some("12".to_string())]
+    );
+}
+
+#[test]
+fn answers_round_trip() {
+    let mut answers = Answers::default();
+    answers.answers.insert((5, 1), "5".to_string());
+    answers.answers.insert((11, 2), "195".to_string());
+    answers
+        .answers
+        .insert((13, 2), "#..#\n# \\ #\r\n####".to_string());
+    let path = env::temp_dir().join(format!("aoc-examples-{}.txt", std::process::id()));
+    answers.save(&path).unwrap();
+    let loaded = Answers::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded, answers);
+}
+
+#[test]
+fn answers_reject_bad_escapes() {
+    let path = env::temp_dir().join(format!("aoc-examples-bad-{}.txt", std::process::id()));
+    std::fs::write(&path, "5 1 5\n13 2 #\\x#\n").unwrap();
+    let error = Answers::load(&path).unwrap_err();
+    std::fs::remove_file(&path).unwrap();
+    assert!(error.to_string().ends_with(":2: malformed answer entry"));
+}
@@ -0,0 +1,96 @@
+mod common;
+
+use advent_of_code_2021::fetch::{FetchError, Fetcher};
+use advent_of_code_2021::input::{self, read_input, InputSource};
+use common::{MockServer, Request};
+use std::env;
+use std::fs;
+use std::net::TcpListener;
+use std::path::PathBuf;
+
+fn cache_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn downloads_and_caches() {
+    let server = MockServer::start(200, "1\n2\n3\n");
+    let dir = cache_dir("download");
+    let fetcher = Fetcher::new(Some("abc".to_string()))
+        .with_base_url(&server.url())
+        .with_cache_dir(&dir);
+
+    let input = fetcher.fetch(7, false).unwrap();
+    assert_eq!(input.text, "1\n2\n3\n");
+    assert_eq!(fs::read_to_string(dir.join("7.txt")).unwrap(), "1\n2\n3\n");
+    assert_eq!(
+        server.requests(),
+        vec![Request {
+            method: "GET".to_string(),
+            url: "/2021/day/7/input".to_string(),
+            cookie: Some("session=abc".to_string()),
+            body: String::new(),
+        }]
+    );
+
+    // The second fetch is served from the cache.
+    fetcher.fetch(7, false).unwrap();
+    assert_eq!(server.requests().len(), 1);
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn falls_back_to_cache_when_offline() {
+    let dir = cache_dir("offline");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("3.txt"), "cached").unwrap();
+    // Nothing listens on a port that was just released.
+    let unreachable = {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        format!("http://{}", listener.local_addr().unwrap())
+    };
+    let fetcher = Fetcher::new(Some("abc".to_string()))
+        .with_base_url(&unreachable)
+        .with_cache_dir(&dir);
+
+    assert_eq!(fetcher.fetch(3, true).unwrap().text, "cached");
+    assert!(matches!(
+        fetcher.fetch(4, false),
+        Err(FetchError::Network(4, _))
+    ));
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn reports_bad_status_and_missing_session() {
+    let server = MockServer::start(404, "Please don't repeatedly request this endpoint");
+    let dir = cache_dir("status");
+    let fetcher = Fetcher::new(Some("abc".to_string()))
+        .with_base_url(&server.url())
+        .with_cache_dir(&dir);
+    assert!(matches!(
+        fetcher.fetch(25, false),
+        Err(FetchError::Status(25, 404))
+    ));
+    assert!(!dir.join("25.txt").exists());
+
+    let fetcher = Fetcher::new(None)
+        .with_base_url(&server.url())
+        .with_cache_dir(&dir);
+    assert!(matches!(
+        fetcher.fetch(1, false),
+        Err(FetchError::NoSession(1))
+    ));
+    assert_eq!(server.requests().len(), 1);
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn caches_where_inputs_are_read() {
+    let path = Fetcher::new(None).cache_path(99);
+    assert_eq!(path, input::inputs_dir().join("99.txt"));
+    let error = read_input(99, &InputSource::Default).unwrap_err();
+    assert!(error.tried.contains(&path));
+}
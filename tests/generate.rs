@@ -0,0 +1,105 @@
+use advent_of_code_2021::generate::{default_size, generate, scanner_layout};
+use advent_of_code_2021::registry::get_day;
+use advent_of_code_2021::{day19, day9};
+
+#[test]
+fn same_seed_same_input() {
+    for day in 1..=23 {
+        assert_eq!(generate(day, 20, 5), generate(day, 20, 5), "day {}", day);
+    }
+    assert_ne!(generate(1, 20, 5), generate(1, 20, 6));
+    assert_eq!(generate(24, 20, 5), None);
+}
+
+fn assert_solvable(day: u32, size: usize) {
+    let input = generate(day, size, 1).unwrap();
+    for part in 1..=2 {
+        let answer = get_day(day).unwrap().run(part, &input);
+        assert!(
+            answer.is_ok(),
+            "day {} part {} at size {}: {}",
+            day,
+            part,
+            size,
+            answer.unwrap_err()
+        );
+    }
+}
+
+#[test]
+fn generated_inputs_are_solvable() {
+    for day in 1..=22 {
+        let size = match day {
+            9 | 11 | 15 | 20 => 10,
+            12 => 6,
+            19 => 3,
+            _ => 50,
+        };
+        assert_solvable(day, size);
+    }
+}
+
+#[test]
+fn generated_grids_are_solvable_when_large() {
+    for day in [9, 11, 15, 20] {
+        assert_solvable(day, 100);
+    }
+}
+
+/// The sizes `aoc-gen` uses by default.
+#[test]
+#[ignore = "slow"]
+fn generated_inputs_are_solvable_at_default_size() {
+    for day in 1..=22 {
+        assert_solvable(day, default_size(day));
+    }
+}
+
+#[test]
+fn generated_basins_are_walled_off() {
+    let map = day9::parse(&generate(9, 100, 1).unwrap()).unwrap();
+    let floor = map.iter().filter(|&&height| height != 9).count();
+    let basins = day9::get_basins(&map);
+    // Basins only cover all the floor between them if none overlap.
+    assert_eq!(basins.iter().map(|b| b.len()).sum::<usize>(), floor);
+    assert!(basins.len() > 100);
+}
+
+#[test]
+fn generated_octopuses_synchronize_quickly() {
+    for size in [1, 10, 1000] {
+        let input = generate(11, size, 1).unwrap();
+        let step: u32 = get_day(11)
+            .unwrap()
+            .run(2, &input)
+            .unwrap()
+            .trim()
+            .parse()
+            .unwrap();
+        assert!(step <= 10, "size {}: {}", size, step);
+    }
+}
+
+#[test]
+#[ignore = "slow"]
+fn generated_amphipods_are_solvable() {
+    let input = generate(23, 1, 1).unwrap();
+    get_day(23).unwrap().run(1, &input).unwrap();
+}
+
+#[test]
+fn sizes_scale_inputs() {
+    assert_eq!(generate(1, 1000, 0).unwrap().lines().count(), 1000);
+    assert_eq!(generate(4, 30, 0).unwrap().matches("\n\n").count(), 30);
+    assert_eq!(generate(9, 40, 0).unwrap().lines().count(), 40);
+    let scanners = generate(19, 6, 0).unwrap();
+    assert_eq!(scanners.matches("--- scanner").count(), 6);
+}
+
+#[test]
+fn scanners_are_found_where_they_were_placed() {
+    let (input, layout) = scanner_layout(6, 1);
+    assert_eq!(generate(19, 6, 1), Some(input.clone()));
+    let mut scans = day19::parse(&input).unwrap();
+    assert_eq!(day19::match_scans(&mut scans).unwrap(), layout);
+}
@@ -0,0 +1,103 @@
+use advent_of_code_2021::examples::Answers;
+use advent_of_code_2021::input::{read_input, InputSource};
+use advent_of_code_2021::registry::get_day;
+use std::path::Path;
+
+fn check(day: u32, source: InputSource, part: usize, expected: &str) {
+    let input = read_input(day, &source).unwrap();
+    let answer = get_day(day).unwrap().run(part, &input.text).unwrap();
+    assert_eq!(
+        answer.trim_end(),
+        expected.trim_end(),
+        "day {} part {}",
+        day,
+        part
+    );
+}
+
+macro_rules! golden {
+    ($($(#[$attr:meta])* $name:ident: $day:expr, $source:ident, $part:expr => $expected:expr;)*) => {
+        $(
+            #[test]
+            $(#[$attr])*
+            fn $name() {
+                check($day, InputSource::$source, $part, $expected);
+            }
+        )*
+    };
+}
+
+const DAY13_CODE: &str = "\
+#....###..####...##.###....##.####.#..#
+#....#..#.#.......#.#..#....#.#....#..#
+#....#..#.###.....#.###.....#.###..####
+#....###..#.......#.#..#....#.#....#..#
+#....#.#..#....#..#.#..#.#..#.#....#..#
+####.#..#.#.....##..###...##..####.#..#";
+
+golden! {
+    day1_part1: 1, Default, 1 => "1754";
+    day1_part2: 1, Default, 2 => "1789";
+    day2_part1: 2, Default, 1 => "1990000";
+    day2_part2: 2, Default, 2 => "1975421260";
+    day3_part1: 3, Default, 1 => "3895776";
+    day3_part2: 3, Default, 2 => "7928162";
+    day4_part1: 4, Default, 1 => "89001";
+    day4_part2: 4, Default, 2 => "7296";
+    day5_part1: 5, Default, 1 => "6225";
+    day5_part2: 5, Default, 2 => "22116";
+    day6_part1: 6, Default, 1 => "374927";
+    day6_part2: 6, Default, 2 => "1687617803407";
+    day7_part1: 7, Default, 1 => "323647";
+    day7_part2: 7, Default, 2 => "87640209";
+    day8_part1: 8, Default, 1 => "512";
+    day8_part2: 8, Default, 2 => "1091165";
+    day9_part1: 9, Default, 1 => "475";
+    day9_part2: 9, Default, 2 => "1092012";
+    day10_part1: 10, Default, 1 => "392367";
+    day10_part2: 10, Default, 2 => "2192104158";
+    day11_part1: 11, Default, 1 => "1640";
+    day11_part2: 11, Default, 2 => "312";
+    day12_part1: 12, Default, 1 => "3421";
+    #[ignore = "slow"]
+    day12_part2: 12, Default, 2 => "84870";
+    day13_part1: 13, Default, 1 => "706";
+    day13_part2: 13, Default, 2 => DAY13_CODE;
+    day14_part1: 14, Default, 1 => "3342";
+    day14_part2: 14, Default, 2 => "3776553567525";
+    day15_part1: 15, Default, 1 => "523";
+    day15_part2: 15, Default, 2 => "2876";
+    day16_part1: 16, Default, 1 => "847";
+    day16_part2: 16, Default, 2 => "333794664059";
+    day17_part1: 17, Default, 1 => "5460";
+    day17_part2: 17, Default, 2 => "3618";
+    day18_part1: 18, Default, 1 => "3654";
+    day18_part2: 18, Default, 2 => "4578";
+    #[ignore = "slow"]
+    day19_part1: 19, Default, 1 => "512";
+    #[ignore = "slow"]
+    day19_part2: 19, Default, 2 => "16802";
+    day20_part1: 20, Default, 1 => "5229";
+    day20_part2: 20, Default, 2 => "17009";
+    day21_part1: 21, Default, 1 => "913560";
+    day21_part2: 21, Default, 2 => "110271560863819";
+    day22_part1: 22, Default, 1 => "603661";
+    #[ignore = "slow"]
+    day22_part2: 22, Default, 2 => "1237264238382479";
+    #[ignore = "slow"]
+    day23_part1: 23, Default, 1 => "10607";
+    #[ignore = "slow"]
+    day23_part2: 23, Default, 2 => "59071";
+}
+
+/// Checks every example answer recorded by `aoc-examples` against its
+/// `N_test.txt` input.
+#[test]
+fn examples() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs/examples.txt");
+    let table = Answers::load(&path).unwrap();
+    assert!(!table.answers.is_empty());
+    for ((day, part), expected) in table.answers.iter() {
+        check(*day, InputSource::Test, *part, expected);
+    }
+}
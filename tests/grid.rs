@@ -0,0 +1,40 @@
+use advent_of_code_2021::grid::Grid;
+
+const DIGITS: &str = "123\n456\n789\n";
+
+#[test]
+fn neighbors_stay_inside() {
+    let grid = Grid::parse_digits(DIGITS).unwrap();
+    let corner = grid.neighbors4((0, 0)).map(|p| grid[p]).collect::<Vec<_>>();
+    assert_eq!(corner, vec![2, 4]);
+    let corner = grid.neighbors8((2, 2)).map(|p| grid[p]).collect::<Vec<_>>();
+    assert_eq!(corner, vec![5, 6, 8]);
+    assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+}
+
+#[test]
+fn row_and_column_views() {
+    let grid = Grid::parse_digits(DIGITS).unwrap();
+    assert_eq!(grid.row(1), &[4, 5, 6]);
+    assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec![3, 6, 9]);
+    assert_eq!(grid.columns().count(), 3);
+    assert_eq!(grid.to_string(), DIGITS.trim_end());
+}
+
+#[test]
+fn parse_reports_position() {
+    let error = Grid::parse_digits("123\n4x6\n").unwrap_err();
+    assert_eq!((error.line, error.column), (2, 2));
+    let error = Grid::parse_digits("123\n45\n").unwrap_err();
+    assert_eq!(error.line, 2);
+    assert!(Grid::parse_digits("").is_err());
+}
+
+#[test]
+fn char_grid() {
+    let grid = Grid::parse_chars("#.\n.#").unwrap();
+    assert_eq!((grid.height(), grid.width()), (2, 2));
+    assert_eq!(grid[(1, 1)], '#');
+    assert_eq!(grid.get((2, 0)), None);
+    assert_eq!(grid.get_signed(-1, 0), None);
+}
@@ -0,0 +1,76 @@
+use advent_of_code_2021::day6::{self, Timers};
+use num::{BigUint, ToPrimitive};
+
+const EXAMPLE: [u8; 5] = [3, 4, 3, 1, 2];
+
+#[test]
+fn matches_example() {
+    let timers = Timers::default();
+    assert_eq!(
+        day6::population_exact(&EXAMPLE, 18, timers),
+        BigUint::from(26u32)
+    );
+    assert_eq!(
+        day6::population_exact(&EXAMPLE, 80, timers),
+        BigUint::from(5934u32)
+    );
+    assert_eq!(
+        day6::population_exact(&EXAMPLE, 256, timers),
+        BigUint::from(26984457539u64)
+    );
+    assert_eq!(day6::population_mod(&EXAMPLE, 256, timers, 1000), 539);
+    assert_eq!(
+        day6::population_exact(&[], 256, timers),
+        BigUint::from(0u32)
+    );
+}
+
+#[test]
+fn exact_beyond_u64() {
+    let timers = Timers::default();
+    let exact = day6::population_exact(&EXAMPLE, 1000, timers);
+    assert!(exact.to_u64().is_none());
+    let modulus = 1_000_000_007;
+    assert_eq!(
+        day6::population_mod(&EXAMPLE, 1000, timers, modulus),
+        (exact % modulus).to_u64().unwrap()
+    );
+    assert_eq!(
+        day6::population_mod(&EXAMPLE, 1000, timers, u64::MAX),
+        (day6::population_exact(&EXAMPLE, 1000, timers) % u64::MAX)
+            .to_u64()
+            .unwrap()
+    );
+}
+
+#[test]
+fn huge_day_counts() {
+    // A single fish's descendants satisfy f(d) = f(d - 7) + f(d - 9).
+    let timers = Timers::default();
+    let modulus = 998_244_353;
+    let f = |days| day6::population_mod(&[0], days, timers, modulus);
+    let days = 1_000_000_000_000;
+    assert_eq!(f(days), (f(days - 7) + f(days - 9)) % modulus);
+}
+
+#[test]
+fn configurable_timers() {
+    // With no wait before spawning again, the population doubles each day.
+    let timers = Timers {
+        reset: 0,
+        newborn: 0,
+    };
+    assert_eq!(
+        day6::population_exact(&[0], 100, timers),
+        BigUint::from(1u32) << 100
+    );
+    let timers = Timers {
+        reset: 2,
+        newborn: 3,
+    };
+    assert_eq!(day6::population_exact(&[9], 9, timers), BigUint::from(1u32));
+    assert_eq!(
+        day6::population_exact(&[9], 10, timers),
+        BigUint::from(2u32)
+    );
+}
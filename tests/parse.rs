@@ -0,0 +1,22 @@
+use advent_of_code_2021::parse::ParseError;
+use advent_of_code_2021::{day10, day16};
+
+#[test]
+fn brackets_reject_non_ascii() {
+    assert_eq!(
+        day10::parse("()\n(é)\n"),
+        Err(ParseError::new(2, 2, "bracket", "é"))
+    );
+}
+
+#[test]
+fn hexadecimal_rejects_non_ascii() {
+    assert_eq!(
+        day16::parse("1é0\n"),
+        Err(ParseError::new(1, 2, "hexadecimal digit", "é"))
+    );
+    assert_eq!(
+        day16::parse("é1\n"),
+        Err(ParseError::new(1, 1, "hexadecimal digit", "é"))
+    );
+}
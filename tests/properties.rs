@@ -0,0 +1,320 @@
+use advent_of_code_2021::day1;
+use advent_of_code_2021::day13::{self, FoldType};
+use advent_of_code_2021::day14;
+use advent_of_code_2021::day22::{self, Cuboid, Range};
+use advent_of_code_2021::day3::{self, Report};
+use advent_of_code_2021::day5::{self, Heatmap, Line, Map, Point};
+use advent_of_code_2021::day6::{self, Timers};
+use advent_of_code_2021::generate::generate;
+use advent_of_code_2021::vec3::Vec3;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::{HashMap, HashSet};
+
+const CASES: u64 = 100;
+
+/// Runs `property` on `cases` seeded generators, naming the failing seed so
+/// a case can be replayed on its own.
+fn check(cases: u64, mut property: impl FnMut(&mut StdRng)) {
+    for seed in 0..cases {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| property(&mut rng)));
+        if let Err(panic) = result {
+            eprintln!("property failed with seed {}", seed);
+            std::panic::resume_unwind(panic);
+        }
+    }
+}
+
+fn element(rng: &mut StdRng) -> char {
+    (b'A' + rng.gen_range(0..5)) as char
+}
+
+#[test]
+fn polymer_step_conserves_pairs() {
+    check(CASES, |rng| {
+        let mut pair_counts = HashMap::new();
+        for _ in 0..rng.gen_range(0..20) {
+            let count = rng.gen_range(1..1_000_000);
+            *pair_counts.entry((element(rng), element(rng))).or_insert(0) += count;
+        }
+        let mut patterns = HashSet::new();
+        let mut rules = Vec::new();
+        for _ in 0..rng.gen_range(0..25) {
+            let pattern = (element(rng), element(rng));
+            let insert = element(rng);
+            if patterns.insert(pattern) {
+                rules.push((pattern, ((pattern.0, insert), (insert, pattern.1))));
+            }
+        }
+
+        let total: u64 = pair_counts.values().sum();
+        let inserts: u64 = patterns
+            .iter()
+            .filter_map(|pattern| pair_counts.get(pattern))
+            .sum();
+        let next = day14::run_step(&pair_counts, &rules);
+        assert_eq!(next.values().sum::<u64>(), total + inserts);
+    });
+}
+
+#[test]
+fn generated_polymer_doubles_pairs() {
+    // Generated rules cover every pair, so each step inserts into all of them.
+    check(10, |rng| {
+        let input = generate(14, 20, rng.gen()).unwrap();
+        let (mut pair_counts, rules) = day14::parse(&input).unwrap();
+        for _ in 0..10 {
+            let total: u64 = pair_counts.values().sum();
+            pair_counts = day14::run_step(&pair_counts, &rules);
+            assert_eq!(pair_counts.values().sum::<u64>(), 2 * total);
+        }
+    });
+}
+
+#[test]
+fn folding_never_adds_points() {
+    check(CASES, |rng| {
+        let (width, height) = (rng.gen_range(1..40) * 2 + 1, rng.gen_range(1..40) * 2 + 1);
+        let mut points: HashSet<(usize, usize)> = (0..rng.gen_range(0..200))
+            .map(|_| (rng.gen_range(0..width), rng.gen_range(0..height)))
+            .collect();
+        let (mut width, mut height) = (width, height);
+        while width > 1 || height > 1 {
+            let (dir, loc) = if height == 1 || (width > 1 && rng.gen()) {
+                (FoldType::Vertical, width / 2)
+            } else {
+                (FoldType::Horizontal, height / 2)
+            };
+            // Dots never appear on a fold line.
+            points.retain(|&(x, y)| match dir {
+                FoldType::Vertical => x != loc,
+                FoldType::Horizontal => y != loc,
+            });
+            let folded = day13::fold_points(&points, dir, loc).unwrap();
+            assert!(folded.len() <= points.len());
+            assert!(folded.iter().all(|&(x, y)| match dir {
+                FoldType::Vertical => x < loc,
+                FoldType::Horizontal => y < loc,
+            }));
+            match dir {
+                FoldType::Vertical => width = loc,
+                FoldType::Horizontal => height = loc,
+            }
+            points = folded;
+        }
+    });
+}
+
+#[test]
+fn fish_buckets_match_naive() {
+    check(CASES, |rng| {
+        let fish: Vec<u8> = (0..rng.gen_range(0..20))
+            .map(|_| rng.gen_range(0..=20))
+            .collect();
+        let days = rng.gen_range(0..60);
+        assert_eq!(
+            day6::simulate_fish(&fish, days),
+            day6::simulate_fish_naive(&fish, days)
+        );
+    });
+}
+
+#[test]
+fn fish_matrix_power_matches_simulation() {
+    check(CASES, |rng| {
+        let timers = Timers {
+            reset: rng.gen_range(0..6),
+            newborn: rng.gen_range(0..10),
+        };
+        let mut fish: Vec<usize> = (0..rng.gen_range(0..10))
+            .map(|_| rng.gen_range(0..12))
+            .collect();
+        let start: Vec<u8> = fish.iter().map(|&timer| timer as u8).collect();
+        let days = rng.gen_range(0..40);
+        for _ in 0..days {
+            let mut newborns = 0;
+            for timer in fish.iter_mut() {
+                if *timer == 0 {
+                    *timer = timers.reset;
+                    newborns += 1;
+                } else {
+                    *timer -= 1;
+                }
+            }
+            fish.extend(std::iter::repeat_n(timers.newborn, newborns));
+        }
+        assert_eq!(
+            day6::population_exact(&start, days, timers),
+            fish.len().into()
+        );
+        if timers == Timers::default() {
+            assert_eq!(day6::simulate_fish(&start, days as u32), fish.len() as u64);
+        }
+    });
+}
+
+#[test]
+fn streamed_windows_match_slices() {
+    check(CASES, |rng| {
+        let readings: Vec<i64> = (0..rng.gen_range(0..50))
+            .map(|_| rng.gen_range(-20..20))
+            .collect();
+        let width = rng.gen_range(1..8);
+        let windows: Vec<_> = day1::windows(readings.iter().copied(), width).collect();
+        assert_eq!(windows.len(), readings.windows(width).count());
+        for (window, slice) in windows.iter().zip(readings.windows(width)) {
+            assert_eq!(window.sum, slice.iter().sum::<i64>());
+            assert_eq!(window.min, *slice.iter().min().unwrap());
+            assert_eq!(window.max, *slice.iter().max().unwrap());
+        }
+    });
+}
+
+/// The rating by filtering a list, as the puzzle describes it.
+fn rating(numbers: &[u64], width: usize, most_common: bool) -> Option<u64> {
+    let mut numbers = numbers.to_vec();
+    for bit in (0..width).rev() {
+        if numbers.len() == 1 {
+            break;
+        }
+        let ones = numbers.iter().filter(|&&n| n >> bit & 1 == 1).count();
+        let keep_ones = (2 * ones >= numbers.len()) == most_common;
+        numbers.retain(|&n| (n >> bit & 1 == 1) == keep_ones);
+        if numbers.is_empty() {
+            return None;
+        }
+    }
+    (numbers.len() == 1).then(|| numbers[0])
+}
+
+#[test]
+fn bit_columns_match_filtering() {
+    check(CASES, |rng| {
+        let width = rng.gen_range(1..=64);
+        let report = Report {
+            width,
+            numbers: (0..rng.gen_range(0..200))
+                .map(|_| rng.gen::<u64>() >> (64 - width) >> rng.gen_range(0..2))
+                .collect(),
+        };
+        let diagnostics = day3::analyze(&report);
+        assert_eq!(
+            diagnostics.oxygen_generator.ok(),
+            rating(&report.numbers, width, true)
+        );
+        assert_eq!(
+            diagnostics.co2_scrubber.ok(),
+            rating(&report.numbers, width, false)
+        );
+        assert_eq!(
+            diagnostics.gamma ^ diagnostics.epsilon,
+            u64::MAX >> (64 - width)
+        );
+    });
+}
+
+#[test]
+fn vent_sweep_matches_naive() {
+    check(CASES, |rng| {
+        let lines: Vec<Line> = (0..rng.gen_range(0..30))
+            .map(|_| {
+                let start = Point {
+                    x: rng.gen_range(-15..15),
+                    y: rng.gen_range(-15..15),
+                };
+                let (dx, dy, len) = if rng.gen() {
+                    (
+                        rng.gen_range(-1..=1),
+                        rng.gen_range(-1..=1),
+                        rng.gen_range(0..20),
+                    )
+                } else {
+                    (
+                        rng.gen_range(-4..=4),
+                        rng.gen_range(-4..=4),
+                        rng.gen_range(0..6),
+                    )
+                };
+                let end = Point {
+                    x: start.x + dx * len,
+                    y: start.y + dy * len,
+                };
+                Line { start, end }
+            })
+            .collect();
+        assert_eq!(day5::coverage(&lines), day5::coverage_naive(&lines));
+    });
+}
+
+#[test]
+fn vent_heatmap_matches_points() {
+    check(CASES, |rng| {
+        let lines: Vec<Line> = (0..rng.gen_range(0..20))
+            .map(|_| {
+                let start = Point {
+                    x: rng.gen_range(-30..30),
+                    y: rng.gen_range(-30..30),
+                };
+                let (dx, dy) = (rng.gen_range(-5..=5), rng.gen_range(-5..=5));
+                let len = rng.gen_range(0..8);
+                let end = Point {
+                    x: start.x + dx * len,
+                    y: start.y + dy * len,
+                };
+                Line { start, end }
+            })
+            .collect();
+        let heatmap = Heatmap::new(&lines, rng.gen_range(1..40));
+        let mut map = Map::new();
+        for line in lines.iter() {
+            map.add_line(line);
+        }
+        let mut cells = vec![0; heatmap.width * heatmap.height];
+        for (point, &count) in map.counts.iter() {
+            let col = (point.x - heatmap.origin.x) as usize / heatmap.scale as usize;
+            let row = (point.y - heatmap.origin.y) as usize / heatmap.scale as usize;
+            let cell = &mut cells[row * heatmap.width + col];
+            *cell = (*cell).max(count);
+        }
+        assert_eq!(heatmap.cells, cells);
+    });
+}
+
+fn range(rng: &mut StdRng) -> Range<i64> {
+    let min = rng.gen_range(-70..70);
+    Range {
+        min,
+        max: min + rng.gen_range(1..40),
+    }
+}
+
+#[test]
+fn compressed_volume_matches_voxels() {
+    const SIZE: usize = 101;
+    check(20, |rng| {
+        let regions: Vec<Cuboid<i64>> = (0..rng.gen_range(1..12))
+            .map(|_| Cuboid {
+                ranges: Vec3::new(range(rng), range(rng), range(rng)),
+                value: rng.gen(),
+            })
+            .collect();
+
+        let mut voxels = vec![false; SIZE * SIZE * SIZE];
+        for region in regions.iter() {
+            let [x, y, z] = region
+                .ranges
+                .to_array()
+                .map(|r| (r.min.max(-50) + 50) as usize..(r.max.min(51) + 50).max(0) as usize);
+            for x in x {
+                for y in y.clone() {
+                    for z in z.clone() {
+                        voxels[(x * SIZE + y) * SIZE + z] = region.value;
+                    }
+                }
+            }
+        }
+        let expected = voxels.iter().filter(|&&on| on).count() as u64;
+        assert_eq!(day22::part1(&regions), expected);
+    });
+}
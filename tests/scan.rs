@@ -0,0 +1,95 @@
+use advent_of_code_2021::grid::Grid;
+use advent_of_code_2021::parse::ParseError;
+use advent_of_code_2021::scan::{self, Rule, Scanner};
+
+#[test]
+fn integers_with_separators() {
+    assert_eq!(
+        scan::integers::<i32>("3,-4, 5\n", 1, b",", "integer"),
+        Ok(vec![3, -4, 5])
+    );
+    assert_eq!(
+        scan::integers::<u64>("199\n200\n\n208", 1, b"\n", "integer"),
+        Ok(vec![199, 200, 208])
+    );
+    assert_eq!(
+        scan::integers::<u8>("1,2\n3,256", 4, b",\n", "byte"),
+        Err(ParseError::new(5, 3, "byte", "256"))
+    );
+    assert_eq!(
+        scan::integers::<u8>("1,-2", 1, b",", "byte"),
+        Err(ParseError::new(1, 3, "byte", "-2"))
+    );
+    assert_eq!(
+        scan::integers::<i64>("1 2", 1, b",", "integer"),
+        Err(ParseError::new(1, 3, "one of \",\"", "2"))
+    );
+    assert_eq!(
+        scan::integers::<i8>("-128,127", 1, b",", "integer"),
+        Ok(vec![-128, 127])
+    );
+    assert_eq!(
+        scan::integers::<u32>("1\r\n2\r\n\r\n3\r\n", 1, b"\n", "integer"),
+        Ok(vec![1, 2, 3])
+    );
+    assert_eq!(
+        scan::integers::<u32>("1\r2", 1, b"\n", "integer"),
+        Err(ParseError::new(1, 3, "one of \"\\n\"", "2"))
+    );
+}
+
+#[test]
+fn scanner_tracks_lines() {
+    let mut scanner = Scanner::starting_at("--- a ---\n1,2\n3;4", 10);
+    assert_eq!(scanner.rest_of_line(), "--- a ---");
+    assert_eq!(scanner.integer::<i32>("x"), Ok(1));
+    scanner.expect(",").unwrap();
+    assert_eq!(scanner.integer::<i32>("y"), Ok(2));
+    scanner.skip_whitespace();
+    assert_eq!(scanner.line(), 12);
+    assert_eq!(scanner.integer::<i32>("x"), Ok(3));
+    assert_eq!(
+        scanner.expect(","),
+        Err(ParseError::new(12, 2, "\",\"", ";"))
+    );
+}
+
+#[test]
+fn sections_and_rules() {
+    let input = "NNCB\n\nCH -> B\nHH -> N\n\n\n\nlast\n";
+    let sections: Vec<_> = scan::sections(input).collect();
+    assert_eq!(
+        sections,
+        vec![(1, "NNCB"), (3, "CH -> B\nHH -> N"), (8, "last")]
+    );
+    let rules = scan::rules(sections[1].1, 3, " -> ").unwrap();
+    assert_eq!(
+        rules[1],
+        Rule {
+            line: 4,
+            text: "HH -> N",
+            key: "HH",
+            value: "N"
+        }
+    );
+    assert_eq!(
+        scan::rules("CH -> B\nHH N", 3, " -> "),
+        Err(ParseError::new(4, 5, "\" -> \"", ""))
+    );
+}
+
+#[test]
+fn large_inputs() {
+    let numbers: Vec<u32> = (0..200_000).map(|i| i * 7 % 10_007).collect();
+    let text = numbers
+        .iter()
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>()
+        .join("\n");
+    assert_eq!(scan::integers(&text, 1, b"\n", "integer"), Ok(numbers));
+
+    let digits = "0123456789".repeat(100);
+    let grid = Grid::parse_digits(&vec![digits.as_str(); 1000].join("\n")).unwrap();
+    assert_eq!((grid.height(), grid.width()), (1000, 1000));
+    assert_eq!(grid[(999, 19)], 9);
+}
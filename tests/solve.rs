@@ -0,0 +1,152 @@
+use advent_of_code_2021::day6;
+use advent_of_code_2021::parse::ParseError;
+use advent_of_code_2021::registry::{get_day, RunError};
+
+fn run(day: u32, part: usize, input: &str) -> Result<String, RunError> {
+    get_day(day).unwrap().run(part, input)
+}
+
+fn solve_error(message: &str) -> Result<String, RunError> {
+    Err(RunError::Solve(message.to_string()))
+}
+
+#[test]
+fn crabs_need_at_least_one_crab() {
+    assert_eq!(run(7, 1, "\n"), solve_error("No crabs to align"));
+    assert_eq!(run(7, 2, "5\n"), Ok("0".to_string()));
+}
+
+#[test]
+fn fish_may_start_with_long_timers() {
+    for days in [0, 5, 12, 30] {
+        assert_eq!(
+            day6::simulate_fish(&[12, 3, 9], days),
+            day6::simulate_fish_naive(&[12, 3, 9], days)
+        );
+    }
+}
+
+#[test]
+fn displays_need_a_consistent_wiring() {
+    let entry = "ab ab ab ab ab ab ab ab ab ab | ab ab ab ab\n";
+    assert_eq!(
+        run(8, 2, entry),
+        solve_error("Segment a fits none of the wires")
+    );
+}
+
+#[test]
+fn height_maps_need_three_basins() {
+    assert_eq!(
+        run(9, 2, "09\n90\n"),
+        solve_error("Only 2 basin(s), need 3")
+    );
+}
+
+#[test]
+fn brackets_must_close_open_chunks() {
+    assert_eq!(
+        run(10, 1, "()\n(])\n())\n"),
+        solve_error("3:3: closing bracket with no chunk open")
+    );
+    assert_eq!(run(10, 2, "(]\n"), solve_error("No line is incomplete"));
+}
+
+#[test]
+fn octopuses_may_never_synchronize() {
+    assert_eq!(
+        run(11, 2, "19\n"),
+        solve_error("The octopuses do not all flash at once within 1000 steps")
+    );
+}
+
+#[test]
+fn caves_need_a_start_and_an_end() {
+    assert_eq!(run(12, 1, "a-b\n"), solve_error("No cave is named start"));
+    assert_eq!(run(12, 2, "start-A\n"), solve_error("No cave is named end"));
+    assert_eq!(run(12, 1, "start-end\n"), Ok("1".to_string()));
+}
+
+#[test]
+fn folds_must_reach_every_dot() {
+    assert_eq!(
+        run(13, 1, "1,2\n"),
+        Err(RunError::Parse(ParseError::new(
+            2,
+            1,
+            "fold instruction",
+            ""
+        )))
+    );
+    assert_eq!(
+        run(13, 1, "5,0\n\nfold along x=1\n"),
+        solve_error("Dot at 5,0 lies beyond the fold along x=1")
+    );
+    assert_eq!(run(13, 1, "2,0\n\nfold along x=1\n"), Ok("1".to_string()));
+}
+
+#[test]
+fn polymers_may_be_a_single_element() {
+    assert_eq!(run(14, 1, "N\n\nNN -> C\n"), Ok("0".to_string()));
+    assert_eq!(run(14, 2, "N\n"), Ok("0".to_string()));
+}
+
+#[test]
+fn risk_levels_start_at_one() {
+    assert_eq!(
+        run(15, 2, "10\n11\n"),
+        Err(RunError::Parse(ParseError::new(
+            1,
+            2,
+            "digit from 1 to 9",
+            "0"
+        )))
+    );
+}
+
+#[test]
+fn packets_must_be_well_formed() {
+    assert_eq!(
+        run(16, 2, "020000\n"),
+        solve_error("Operator type 0 has no operands")
+    );
+    assert_eq!(
+        run(16, 2, "1600C408811020\n"),
+        solve_error("Operator type 5 has 3 operands, not 2")
+    );
+    assert_eq!(
+        run(16, 2, "00\n"),
+        solve_error("Transmission has no complete packet")
+    );
+    assert_eq!(run(16, 1, "00\n"), Ok("0".to_string()));
+}
+
+#[test]
+fn scanners_must_see_beacons() {
+    assert_eq!(
+        run(19, 1, "--- scanner 0 ---\n0,0,0\n\n--- scanner 1 ---\n"),
+        Err(RunError::Parse(ParseError::new(
+            5,
+            1,
+            "beacon position",
+            ""
+        )))
+    );
+    assert_eq!(
+        run(19, 1, ""),
+        Err(RunError::Parse(ParseError::new(1, 1, "scanner header", "")))
+    );
+}
+
+#[test]
+fn scanners_may_share_too_few_beacons() {
+    let scans = "--- scanner 0 ---\n0,0,0\n\n--- scanner 1 ---\n1,1,1\n";
+    assert_eq!(
+        run(19, 2, scans),
+        solve_error("2 scans share no 12 beacons with each other")
+    );
+    assert_eq!(
+        run(19, 1, "--- scanner 0 ---\n0,0,0\n"),
+        Ok("1".to_string())
+    );
+}
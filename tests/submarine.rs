@@ -0,0 +1,62 @@
+use advent_of_code_2021::day2::{self, Aim, Command, Direction, Movement, Plain, Position};
+use advent_of_code_2021::parse::ParseError;
+
+const COMMANDS: &str = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2\n";
+
+fn position(horizontal: i64, depth: i64) -> Position {
+    Position { horizontal, depth }
+}
+
+#[test]
+fn built_in_models() {
+    let trajectory = day2::interpret(&mut Plain, COMMANDS).unwrap();
+    assert_eq!(trajectory.positions.len(), 7);
+    assert_eq!(trajectory.positions[0], Position::default());
+    assert_eq!(trajectory.positions[3], position(13, 5));
+    assert_eq!(trajectory.end(), position(15, 10));
+
+    let commands = day2::parse(COMMANDS).unwrap();
+    let mut aim = Aim::default();
+    assert_eq!(day2::run(&mut aim, &commands).end(), position(15, 60));
+    assert_eq!(aim.aim, 10);
+}
+
+/// Moves like `Plain`, but cannot rise above the surface.
+struct Surface;
+
+impl Movement for Surface {
+    fn apply(&mut self, position: Position, command: Command) -> Position {
+        let mut position = Plain.apply(position, command);
+        position.depth = position.depth.max(0);
+        position
+    }
+}
+
+#[test]
+fn user_defined_model() {
+    let commands = [
+        (Direction::Up, 3),
+        (Direction::Forward, 1),
+        (Direction::Down, 2),
+    ];
+    let depths: Vec<i64> = day2::run(&mut Surface, &commands)
+        .positions
+        .iter()
+        .map(|p| p.depth)
+        .collect();
+    assert_eq!(depths, vec![0, 0, 0, 2]);
+}
+
+#[test]
+fn rejects_unknown_commands() {
+    let mut aim = Aim::default();
+    assert_eq!(
+        day2::interpret(&mut aim, "down 3\nbackward 2\n"),
+        Err(ParseError::new(2, 1, "up, down or forward", "backward"))
+    );
+    assert_eq!(aim.aim, 0);
+    assert_eq!(
+        day2::parse("down 1\nForward 5"),
+        Err(ParseError::new(2, 1, "up, down or forward", "Forward"))
+    );
+}
@@ -0,0 +1,105 @@
+mod common;
+
+use advent_of_code_2021::submit::{Check, Ledger, Response, SubmitError, Submitter, Verdict};
+use common::MockServer;
+use std::env;
+
+const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too high. \
+Please wait one minute before trying again.</p></article></main>";
+
+#[test]
+fn parses_responses() {
+    let page = |text: &str| {
+        format!(
+            "<html><main><article><p>{}</p></article></main></html>",
+            text
+        )
+    };
+    assert_eq!(
+        Response::parse(&page(
+            "That's the right answer! You are one gold star closer."
+        )),
+        Response::Verdict(Verdict::Correct)
+    );
+    assert_eq!(
+        Response::parse(TOO_HIGH),
+        Response::Verdict(Verdict::TooHigh)
+    );
+    assert_eq!(
+        Response::parse(&page(
+            "That's not the right answer; your answer is too low."
+        )),
+        Response::Verdict(Verdict::TooLow)
+    );
+    assert_eq!(
+        Response::parse(&page("That's not the right answer. If you're stuck, ...")),
+        Response::Verdict(Verdict::Wrong)
+    );
+    assert_eq!(
+        Response::parse(&page(
+            "You gave an answer too recently. You have 41s left to wait."
+        )),
+        Response::TooSoon(Some("41s".to_string()))
+    );
+    assert_eq!(
+        Response::parse(&page("You don't seem to be solving the right level.")),
+        Response::WrongLevel
+    );
+    assert!(matches!(
+        Response::parse("<html></html>"),
+        Response::Unknown(_)
+    ));
+}
+
+#[test]
+fn ledger_checks_duplicates_and_bounds() {
+    let mut ledger = Ledger::default();
+    ledger.record(3, 1, "100", Verdict::TooLow);
+    ledger.record(3, 1, "500", Verdict::TooHigh);
+    ledger.record(3, 1, "300", Verdict::TooHigh);
+    ledger.record(3, 1, "250", Verdict::Wrong);
+
+    assert_eq!(ledger.bounds(3, 1), (Some(100), Some(300)));
+    assert_eq!(ledger.check(3, 1, "250"), Check::Submitted(Verdict::Wrong));
+    assert_eq!(ledger.check(3, 1, "200"), Check::Unknown);
+    assert_eq!(
+        ledger.check(3, 1, "400"),
+        Check::OutOfBounds(Some(100), Some(300))
+    );
+    assert_eq!(
+        ledger.check(3, 1, "42"),
+        Check::OutOfBounds(Some(100), Some(300))
+    );
+    assert_eq!(ledger.check(3, 2, "42"), Check::Unknown);
+
+    ledger.record(3, 1, "200", Verdict::Correct);
+    assert_eq!(ledger.check(3, 1, "201"), Check::Solved("200".to_string()));
+
+    let path = env::temp_dir().join(format!("aoc-ledger-{}.txt", std::process::id()));
+    ledger.save(&path).unwrap();
+    let loaded = Ledger::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded, ledger);
+}
+
+#[test]
+fn submits_form_to_server() {
+    let server = MockServer::start(200, TOO_HIGH);
+    let submitter = Submitter::new(Some("abc".to_string())).with_base_url(&server.url());
+    assert_eq!(
+        submitter.submit(5, 2, "22116").unwrap(),
+        Response::Verdict(Verdict::TooHigh)
+    );
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].url, "/2021/day/5/answer");
+    assert_eq!(requests[0].cookie.as_deref(), Some("session=abc"));
+    assert_eq!(requests[0].body, "level=2&answer=22116");
+
+    let submitter = Submitter::new(None).with_base_url(&server.url());
+    assert!(matches!(
+        submitter.submit(5, 2, "1"),
+        Err(SubmitError::NoSession)
+    ));
+}
@@ -0,0 +1,69 @@
+use advent_of_code_2021::vec3::{Rotation, Vec3};
+use std::collections::HashSet;
+
+#[test]
+fn rotation_group_is_proper() {
+    let rotations = Rotation::all();
+    assert_eq!(rotations.len(), 24);
+    assert_eq!(rotations[0], Rotation::IDENTITY);
+    assert_eq!(rotations.iter().collect::<HashSet<_>>().len(), 24);
+    assert!(rotations.iter().all(|r| r.determinant() == 1));
+}
+
+#[test]
+fn rotation_group_is_closed() {
+    let rotations = Rotation::all();
+    let set = rotations.iter().collect::<HashSet<_>>();
+    for a in rotations {
+        assert!(set.contains(&a.inverse()));
+        assert_eq!(a.compose(&a.inverse()), Rotation::IDENTITY);
+        for b in rotations {
+            assert!(set.contains(&a.compose(b)));
+        }
+    }
+}
+
+#[test]
+fn rotations_are_distinct_on_a_point() {
+    let p = Vec3::new(1, 2, 3);
+    let images = Rotation::all()
+        .iter()
+        .map(|r| r.apply(p))
+        .collect::<HashSet<_>>();
+    assert_eq!(images.len(), 24);
+    assert!(images.iter().all(|q| q.manhattan() == 6));
+}
+
+#[test]
+fn quarter_turns() {
+    assert_eq!(
+        Rotation::QUARTER_X.apply(Vec3::new(0, 1, 0)),
+        Vec3::new(0, 0, 1)
+    );
+    assert_eq!(
+        Rotation::QUARTER_Y.apply(Vec3::new(0, 0, 1)),
+        Vec3::new(1, 0, 0)
+    );
+    let r = Rotation::QUARTER_X.compose(&Rotation::QUARTER_Y);
+    let p = Vec3::new(4, -5, 6);
+    assert_eq!(
+        r.apply(p),
+        Rotation::QUARTER_X.apply(Rotation::QUARTER_Y.apply(p))
+    );
+}
+
+#[test]
+fn arithmetic() {
+    let a = Vec3::new(1, -2, 3);
+    let b = Vec3::new(-4, 5, 6);
+    assert_eq!(a + b, Vec3::new(-3, 3, 9));
+    assert_eq!(a - b, Vec3::new(5, -7, -3));
+    assert_eq!(-a, Vec3::new(-1, 2, -3));
+    assert_eq!(a * 2, Vec3::new(2, -4, 6));
+    assert_eq!(a.manhattan_to(&b), 15);
+    let mut c = a;
+    c += b;
+    c -= a;
+    assert_eq!(c, b);
+    assert_eq!(c[1], 5);
+}
@@ -0,0 +1,214 @@
+use advent_of_code_2021::day5::{self, Coverage, Heatmap, Intersection, Line, Point, MAX_SLANTED};
+use advent_of_code_2021::input::{read_input, InputSource};
+use advent_of_code_2021::parse::ParseError;
+use num::rational::Ratio;
+
+fn line(x1: i64, y1: i64, x2: i64, y2: i64) -> Line {
+    Line {
+        start: Point { x: x1, y: y1 },
+        end: Point { x: x2, y: y2 },
+    }
+}
+
+#[test]
+fn sweep_matches_naive_on_example() {
+    let input = read_input(5, &InputSource::Test).unwrap();
+    let lines = day5::parse(&input.text).unwrap();
+    let coverage = day5::coverage(&lines);
+    assert_eq!(coverage, day5::coverage_naive(&lines));
+    assert_eq!(coverage.overlaps(), 12);
+    assert_eq!(coverage.at_least(3), 2);
+    let axis_aligned = lines.iter().filter(|line| line.is_axis_aligned());
+    assert_eq!(day5::coverage(axis_aligned).overlaps(), 5);
+}
+
+#[test]
+fn counts_by_multiplicity() {
+    let lines = [
+        line(0, 0, 4, 0),
+        line(2, 0, 6, 0),
+        line(3, -2, 3, 2),
+        line(1, -1, 5, 3),
+        line(5, -3, 1, 1),
+    ];
+    let coverage = day5::coverage(&lines);
+    assert_eq!(coverage, day5::coverage_naive(&lines));
+    assert_eq!(
+        coverage,
+        Coverage {
+            points: [(1, 12), (2, 3), (3, 1), (4, 1)].into_iter().collect()
+        }
+    );
+}
+
+#[test]
+fn handles_huge_coordinates() {
+    let far = 1_000_000_000_000_000;
+    let lines = [
+        line(-far, 0, far, 0),
+        line(-far, 1, far, 1),
+        line(5, -far, 5, far),
+        line(-far, -far, far, far),
+        line(i64::MIN, i64::MAX, i64::MAX, i64::MIN),
+        line(0, 10, 10, 0),
+    ];
+    let coverage = day5::coverage(&lines);
+    assert_eq!(coverage.at_least(3), 1);
+    assert_eq!(coverage.overlaps(), 10);
+    assert_eq!(coverage.at_least(1), 4 * (2 * far as u128 + 1) + (1 << 64));
+}
+
+#[test]
+fn sweeps_many_parallel_lines() {
+    // Every horizontal line is active at once, which a scan of all active
+    // pieces would compare pairwise.
+    let count = 100_000;
+    let mut lines: Vec<Line> = (0..count).map(|y| line(0, y, 10, y)).collect();
+    lines.push(line(5, 0, 5, count - 1));
+    let coverage = day5::coverage(&lines);
+    assert_eq!(coverage.overlaps(), count as u128);
+    assert_eq!(coverage.at_least(1), 11 * count as u128);
+}
+
+#[test]
+fn steps_along_any_slope() {
+    let points = line(0, 0, 6, -4).get_points();
+    assert_eq!(
+        points,
+        vec![
+            Point { x: 0, y: 0 },
+            Point { x: 3, y: -2 },
+            Point { x: 6, y: -4 }
+        ]
+    );
+    assert_eq!(line(6, -4, 0, 0).direction(), line(0, 0, 6, -4).direction());
+    assert_eq!(line(2, 2, 2, 2).get_points(), vec![Point { x: 2, y: 2 }]);
+
+    let lines = day5::parse("0,0 -> 6,4\n0,4 -> 6,0\n3,0 -> 3,4\n0,2 -> 6,2\n1,0 -> 5,4").unwrap();
+    let coverage = day5::coverage(&lines);
+    assert_eq!(coverage, day5::coverage_naive(&lines));
+    assert_eq!(coverage.at_least(5), 1);
+}
+
+#[test]
+fn intersects_exactly() {
+    assert_eq!(
+        line(0, 0, 3, 1).intersection(&line(0, 1, 3, 0)),
+        Some(Intersection::Point(Ratio::new(3, 2), Ratio::new(1, 2)))
+    );
+    assert_eq!(line(0, 0, 3, 1).intersection(&line(4, 0, 5, 3)), None);
+    assert_eq!(
+        line(0, 0, 9, 3).intersection(&line(12, 4, 3, 1)),
+        Some(Intersection::Overlap(
+            Point { x: 3, y: 1 },
+            Point { x: 9, y: 3 }
+        ))
+    );
+    assert_eq!(line(0, 0, 9, 3).intersection(&line(0, 1, 9, 4)), None);
+    let far = i64::MAX;
+    assert_eq!(
+        line(-far, -far, far, far).intersection(&line(-7, 3, 7, -1)),
+        Some(Intersection::Point(Ratio::new(7, 9), Ratio::new(7, 9)))
+    );
+}
+
+#[test]
+fn rejects_slanted_lines_far_away() {
+    assert!(day5::parse("0,0 -> 1000000,3\n-5,5 -> 5,-5\n").is_ok());
+    let far = format!("0,0 -> {},1", MAX_SLANTED + 1);
+    assert_eq!(
+        day5::parse(&far).unwrap_err(),
+        ParseError::new(
+            1,
+            1,
+            "coordinates within 2147483648 of the origin on a slanted line",
+            &far
+        )
+    );
+}
+
+#[test]
+fn heatmap_crops_and_shrinks() {
+    let lines = [
+        line(10, 5, 13, 5),
+        line(12, 4, 12, 7),
+        line(100, 100, 100, 100),
+    ];
+    let heatmap = Heatmap::new(&lines, 1000);
+    assert_eq!(heatmap.origin, Point { x: 10, y: 4 });
+    assert_eq!((heatmap.width, heatmap.height, heatmap.scale), (91, 97, 1));
+    assert_eq!(heatmap.max(), 2);
+
+    let heatmap = Heatmap::new(&lines, 3);
+    assert_eq!((heatmap.width, heatmap.height, heatmap.scale), (3, 3, 33));
+    assert_eq!(
+        heatmap.to_ascii(),
+        "x 10..108, y 4..102, 33 point(s) per cell\n\
+         @  \n   \n  .\n\
+         Legend: ' ' 0, '.' 1, '@' 2\n"
+    );
+
+    let pgm = heatmap.to_pgm();
+    let header = b"P5\n3 5\n255\n";
+    assert_eq!(&pgm[..header.len()], header);
+    assert_eq!(
+        &pgm[header.len()..],
+        &[255, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 64, 255]
+    );
+    let ppm = heatmap.to_ppm();
+    assert!(ppm.starts_with(b"P6\n3 5\n255\n"));
+    assert!(ppm.ends_with(&[255, 255, 255]));
+}
+
+#[test]
+fn heatmap_of_example() {
+    let input = read_input(5, &InputSource::Test).unwrap();
+    let lines = day5::parse(&input.text).unwrap();
+    let ascii = Heatmap::new(&lines, 80).to_ascii();
+    let rows: Vec<&str> = ascii.lines().collect();
+    assert_eq!(rows[0], "x 0..9, y 0..9, 1 point(s) per cell");
+    assert_eq!(rows[5], " ..+@.@+..");
+    assert_eq!(rows[11], "Legend: ' ' 0, '.' 1, '+' 2, '@' 3");
+}
+
+#[test]
+fn heatmap_of_huge_coordinates() {
+    // The diagonals cross between lattice points, each meets the vertical
+    // line at one.
+    let lines = [
+        line(i64::MIN, i64::MIN, i64::MAX, i64::MAX),
+        line(i64::MIN, i64::MAX, i64::MAX, i64::MIN),
+        line(0, i64::MIN, 0, i64::MAX),
+    ];
+    let heatmap = Heatmap::new(&lines, 4);
+    assert_eq!(heatmap.scale, 1 << 62);
+    assert_eq!(
+        heatmap.to_ascii(),
+        format!(
+            "x {}..{}, y {}..{}, {} point(s) per cell\n\
+             . ..\n .@ \n .@ \n. ..\n\
+             Legend: ' ' 0, '.' 1, '@' 2\n",
+            i64::MIN,
+            i64::MAX,
+            i64::MIN,
+            i64::MAX,
+            1u64 << 62
+        )
+    );
+}
+
+#[test]
+fn heatmap_at_full_size() {
+    let input = read_input(5, &InputSource::Default).unwrap();
+    let lines = day5::parse(&input.text).unwrap();
+    let heatmap = Heatmap::new(&lines, 1000);
+    assert_eq!(heatmap.scale, 1);
+    assert_eq!(heatmap.to_ascii().lines().count(), heatmap.height + 2);
+    let strip = (heatmap.height / 20).max(1);
+    let pixels = heatmap.width * (heatmap.height + 1 + strip);
+    let pgm = heatmap.to_pgm();
+    assert!(pgm.starts_with(b"P5\n"));
+    assert!(pgm.ends_with(&[255]));
+    let ppm = heatmap.to_ppm();
+    assert_eq!(ppm.len() - pgm.len(), 2 * pixels);
+}